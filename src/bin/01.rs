@@ -43,8 +43,13 @@ impl TryFrom<&str> for Operation {
     type Error = OperationParsingError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (dir, num) = value.split_at(1);
-        if dir.len() != 1 || num.is_empty() {
+        let mut chars = value.chars();
+        let (Some(dir), num) = (chars.next(), chars.as_str()) else {
+            return Err(OperationParsingError::InvalidShape {
+                found: value.into(),
+            });
+        };
+        if num.is_empty() {
             return Err(OperationParsingError::InvalidShape {
                 found: value.into(),
             });
@@ -54,7 +59,7 @@ impl TryFrom<&str> for Operation {
             .parse()
             .map_err(|_| OperationParsingError::InvalidOffset { found: num.into() })?;
 
-        let res = match dir.chars().next().unwrap() {
+        let res = match dir {
             'L' => Operation::Left(num),
             'R' => Operation::Right(num),
             _ => {
@@ -149,8 +154,72 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn operations(rng: &mut Rng) -> String {
+        (0..rng.range(1..20))
+            .map(|_| format!("{}{}", rng.choose(&['L', 'R']), rng.range(0..1000)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&operations, |input| Operations::try_from(input.lines()));
+    }
+
+    /// Turns the dial one click at a time, returning the positions after each click grouped by rotation.
+    fn reference_clicks(input: &str) -> Vec<Vec<usize>> {
+        let mut position = 50;
+
+        input
+            .lines()
+            .map(|line| {
+                let (dir, clicks) = line.split_at(1);
+                let clicks: usize = clicks.parse().unwrap();
+                (0..clicks)
+                    .map(|_| {
+                        position = match dir {
+                            "L" => (position + 99) % 100,
+                            _ => (position + 1) % 100,
+                        };
+                        position
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn reference_part_one(input: &str) -> Option<u64> {
+        let clicks = reference_clicks(input);
+        let mut position = 50;
+        let mut zeros = 0;
+        for rotation in clicks {
+            position = *rotation.last().unwrap_or(&position);
+            if position == 0 {
+                zeros += 1;
+            }
+        }
+        Some(zeros)
+    }
+
+    fn reference_part_two(input: &str) -> Option<usize> {
+        let clicks = reference_clicks(input);
+        Some(clicks.iter().flatten().filter(|&&p| p == 0).count())
+    }
+
+    #[test]
+    fn part_one_matches_reference() {
+        fuzz::assert_matches_reference(&operations, reference_part_one, part_one);
+    }
+
+    #[test]
+    fn part_two_matches_reference() {
+        fuzz::assert_matches_reference(&operations, reference_part_two, part_two);
+    }
+
     #[test]
     fn apply_overflow() {
         let mut state = LockState(95);
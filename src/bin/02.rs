@@ -150,8 +150,66 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn id_ranges(rng: &mut Rng) -> String {
+        (0..rng.range(1..6))
+            .map(|_| {
+                let start = rng.range(1..100_000);
+                let end = start + rng.range(0..500);
+                format!("{start}-{end}")
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&id_ranges, |input| input.trim_end().parse::<IdRanges>());
+    }
+
+    /// Sums the ids of all ranges in the input whose decimal representation is invalid.
+    fn reference_sum(input: &str, is_invalid: impl Fn(&str) -> bool) -> Option<usize> {
+        let sum = input
+            .trim_end()
+            .split(',')
+            .flat_map(|range| {
+                let (start, end) = range.split_once('-').unwrap();
+                start.parse::<usize>().unwrap()..=end.parse::<usize>().unwrap()
+            })
+            .filter(|id| is_invalid(&id.to_string()))
+            .sum();
+
+        Some(sum)
+    }
+
+    fn reference_part_one(input: &str) -> Option<usize> {
+        reference_sum(input, |id| {
+            let (first, second) = id.split_at(id.len() / 2);
+            first == second
+        })
+    }
+
+    fn reference_part_two(input: &str) -> Option<usize> {
+        // a string is a repetition of a shorter chunk iff it occurs in itself rotated.
+        reference_sum(input, |id| {
+            let doubled = format!("{id}{id}");
+            doubled[1..doubled.len() - 1].contains(id)
+        })
+    }
+
+    #[test]
+    fn part_one_matches_reference() {
+        fuzz::assert_matches_reference(&id_ranges, reference_part_one, part_one);
+    }
+
+    #[test]
+    fn part_two_matches_reference() {
+        fuzz::assert_matches_reference(&id_ranges, reference_part_two, part_two);
+    }
+
     // 11-22 has two invalid IDs, 11 and 22.
     #[test]
     fn first_invalid_ids() {
@@ -16,6 +16,8 @@ impl AsRef<u8> for Battery {
 enum BatteryParsingError {
     #[error("Invalid battery format: {found} but expected decimal digit")]
     InvalidBatteryFormat { found: char },
+    #[error("Empty bank, expected at least one battery")]
+    EmptyBank,
 }
 
 impl TryFrom<char> for Battery {
@@ -49,18 +51,17 @@ impl Bank {
 
     pub fn max_n_combinations(&self, n: usize) -> u64 {
         let mut start = 0;
-        let mut end = self.0.len() - n + 1;
+        let first_end = self.0.len() - n + 1;
         let mut sum = 0;
 
-        debug_assert!(start < end);
+        debug_assert!(start < first_end);
 
-        for _ in 1..=n {
+        for end in first_end..first_end + n {
             let (next_start, val) = self.max_from(start, end);
 
             sum = sum * 10 + val as u64;
 
             start = next_start + 1;
-            end += 1;
         }
 
         sum
@@ -94,6 +95,10 @@ impl FromStr for Bank {
             .map(Battery::try_from)
             .collect::<Result<Vec<_>, Self::Err>>()?;
 
+        if batteries.is_empty() {
+            return Err(BatteryParsingError::EmptyBank);
+        }
+
         Ok(Self(batteries))
     }
 }
@@ -142,8 +147,25 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn banks(rng: &mut Rng) -> String {
+        (0..rng.range(1..6))
+            .map(|_| {
+                let batteries = rng.range(12..30);
+                rng.string_from(&['1', '2', '3', '4', '5', '6', '7', '8', '9'], batteries)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&banks, |input| input.parse::<Banks>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
@@ -1,5 +1,6 @@
 use std::{ops::Deref, str::FromStr};
 
+use advent_of_code::template::visualize::{self, Frame};
 use thiserror::Error;
 
 advent_of_code::solution!(4);
@@ -78,6 +79,13 @@ impl Grid {
     fn indices(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
         (0..self.0.len()).flat_map(move |row| (0..self.0[0].0.len()).map(move |col| (row, col)))
     }
+
+    fn frame(&self) -> Frame {
+        let width = self.0.first().map_or(0, |row| row.len());
+        Frame::from_fn(self.0.len(), width, |row, col| {
+            if self.0[row].0[col] { '@' } else { '.' }
+        })
+    }
 }
 
 impl AsRef<Vec<Row>> for Grid {
@@ -90,6 +98,8 @@ impl AsRef<Vec<Row>> for Grid {
 enum GridParseError {
     #[error("Unexpected input: {unexpected} was looking for @ or .")]
     UnexpectedInput { unexpected: char },
+    #[error("Row of width {found}, expected all rows to be {expected} wide")]
+    RaggedRows { expected: usize, found: usize },
 }
 
 impl FromStr for Row {
@@ -115,8 +125,20 @@ impl FromStr for Grid {
     type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rows = s.lines().map(|r| r.parse()).collect::<Result<Vec<_>, _>>();
-        Ok(Self(rows?))
+        let rows = s
+            .lines()
+            .map(|r| r.parse())
+            .collect::<Result<Vec<Row>, _>>()?;
+
+        let expected = rows.first().map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().find(|row| row.len() != expected) {
+            return Err(GridParseError::RaggedRows {
+                expected,
+                found: row.len(),
+            });
+        }
+
+        Ok(Self(rows))
     }
 }
 
@@ -164,6 +186,12 @@ fn remove_one_round(grid: &mut Grid) -> usize {
         }
     }
 
+    visualize::emit(|| {
+        grid.frame()
+            .highlight_all(indices_to_remove.iter().copied())
+            .caption(format!("removing {count} rolls"))
+    });
+
     for (row, col) in indices_to_remove {
         debug_assert!(grid.get_cell(row, col) == Some(true));
         grid.set_cell(row, col, false);
@@ -174,8 +202,24 @@ fn remove_one_round(grid: &mut Grid) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn grid(rng: &mut Rng) -> String {
+        let width = rng.range(1..20);
+
+        (0..rng.range(1..20))
+            .map(|_| rng.string_from(&['@', '.'], width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&grid, |input| input.parse::<Grid>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
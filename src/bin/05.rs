@@ -5,7 +5,7 @@ use std::{
 
 use thiserror::Error;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse);
 
 #[derive(Debug, Clone)]
 struct FreshIngredients(RangeInclusive<usize>);
@@ -59,6 +59,12 @@ impl FromStr for FreshIngredients {
         let from = from.parse::<usize>()?;
         let to = to.parse::<usize>()?;
 
+        if from > to {
+            return Err(DatabaseParsingError::InvalidRange {
+                found: s.to_string(),
+            });
+        }
+
         Ok(FreshIngredients(from..=to))
     }
 }
@@ -120,6 +126,9 @@ enum DatabaseParsingError {
 
     #[error("Ingredient parsing error: {0}")]
     InvalidIngredient(#[from] std::num::ParseIntError),
+
+    #[error("no fresh ingredient ranges found")]
+    NoFreshIngredients,
 }
 
 impl FromStr for Database {
@@ -142,6 +151,10 @@ impl FromStr for Database {
             }
         }
 
+        if fresh_ingredients.is_empty() {
+            return Err(DatabaseParsingError::NoFreshIngredients);
+        }
+
         let available_ingredients = lines
             .map(|l| l.parse::<usize>())
             .collect::<Result<Vec<_>, _>>()?;
@@ -153,8 +166,12 @@ impl FromStr for Database {
     }
 }
 
+fn parse(input: &str) -> Option<Database> {
+    input.parse().ok()
+}
+
 pub fn part_one(input: &str) -> Option<usize> {
-    let mut db: Database = input.parse().expect("valid database");
+    let mut db = parse(input)?;
 
     while db.compact_fresh_ingredients() {}
 
@@ -164,7 +181,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut db: Database = input.parse().expect("valid database");
+    let mut db = parse(input)?;
 
     while db.compact_fresh_ingredients() {}
 
@@ -175,8 +192,29 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn database(rng: &mut Rng) -> String {
+        let fresh = (0..rng.range(1..8))
+            .map(|_| {
+                let start = rng.range(1..1000);
+                format!("{start}-{}", start + rng.range(0..100))
+            })
+            .collect::<Vec<_>>();
+        let available = (0..rng.range(0..8))
+            .map(|_| rng.range(1..1200).to_string())
+            .collect::<Vec<_>>();
+
+        format!("{}\n\n{}", fresh.join("\n"), available.join("\n"))
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&database, |input| input.parse::<Database>());
+    }
+
     #[test]
     fn range_len() {
         assert_eq!(FreshIngredients(2..=5).len(), 4);
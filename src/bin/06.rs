@@ -51,6 +51,12 @@ enum MathProblemParseError {
 
     #[error("number after operator")]
     NumberAfterOperator,
+
+    #[error("empty worksheet")]
+    Empty,
+
+    #[error("column at {column} is not aligned with the operators")]
+    MisalignedColumn { column: usize },
 }
 
 impl FromStr for MathProblemSet<PartOne> {
@@ -60,7 +66,7 @@ impl FromStr for MathProblemSet<PartOne> {
         let mut lines = s.lines();
         let cols: Vec<usize> = lines
             .next()
-            .unwrap()
+            .ok_or(MathProblemParseError::Empty)?
             .split_whitespace()
             .map(|el| el.parse())
             .collect::<Result<Vec<_>, _>>()?;
@@ -98,7 +104,13 @@ impl FromStr for MathProblemSet<PartOne> {
                 if let Ok(op) = op {
                     operators.push(op)
                 } else if let Ok(num) = num {
-                    results[index].push(num);
+                    results
+                        .get_mut(index)
+                        .ok_or(MathProblemParseError::MismatchInProblemSizes {
+                            problem: index + 1,
+                            expected: width,
+                        })?
+                        .push(num);
                 } else if operator_seen {
                     return Err(op.err().unwrap());
                 } else {
@@ -114,7 +126,7 @@ impl FromStr for MathProblemSet<PartOne> {
             });
         }
 
-        let expected_len = results.first().unwrap().len();
+        let expected_len = results.first().map_or(0, Vec::len);
 
         let mut problems = Vec::with_capacity(width);
         for (index, problem) in results.into_iter().enumerate() {
@@ -144,22 +156,23 @@ impl FromStr for MathProblemSet<PartTwo> {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines_rev = s.lines().rev();
 
-        let op_line = lines_rev.next().unwrap();
+        let op_line = lines_rev.next().ok_or(MathProblemParseError::Empty)?;
 
         let indices_and_operators: Vec<(usize, Operator)> = op_line
-            .chars()
-            .enumerate()
+            .char_indices()
             .filter_map(|(idx, c)| {
                 let maybe_op = match c {
-                    '+' => Some(Operator::Add),
-                    '*' => Some(Operator::Multiply),
-                    ' ' => None,
-                    _ => panic!("this should be handled better 😱"),
+                    '+' => Ok(Some(Operator::Add)),
+                    '*' => Ok(Some(Operator::Multiply)),
+                    ' ' => Ok(None),
+                    invalid => Err(MathProblemParseError::InvalidOperator {
+                        found: invalid.into(),
+                    }),
                 };
 
-                maybe_op.map(|op| (idx, op))
+                maybe_op.map(|op| op.map(|op| (idx, op))).transpose()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         let number_of_problems = indices_and_operators.len();
 
@@ -167,7 +180,15 @@ impl FromStr for MathProblemSet<PartTwo> {
             indices_and_operators.iter().map(|_| Vec::new()).collect();
         for line in s.lines() {
             for (problem_idx, (string_idx, _)) in indices_and_operators.iter().enumerate() {
-                let (_, nums) = line.split_at(*string_idx);
+                // lines may be shorter than the operator line when trailing whitespace was trimmed.
+                let nums = if *string_idx >= line.len() {
+                    ""
+                } else {
+                    line.get(*string_idx..)
+                        .ok_or(MathProblemParseError::MisalignedColumn {
+                            column: *string_idx,
+                        })?
+                };
 
                 let mut seen_digit = false;
                 for (num_idx, num_char) in nums.chars().enumerate() {
@@ -225,8 +246,57 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    /// Generates a worksheet of aligned problems, with numbers of varying width and alignment.
+    fn worksheet(rng: &mut Rng) -> String {
+        let rows = rng.range(1..5);
+        let problems = rng.range(1..6);
+
+        let mut lines = vec![String::new(); rows + 1];
+
+        for problem in 0..problems {
+            let width = rng.range(1..5);
+            // at least one number spans the full width of the problem.
+            let widest = rng.range(0..rows);
+
+            for (row, line) in lines.iter_mut().take(rows).enumerate() {
+                let digits = if row == widest {
+                    width
+                } else {
+                    rng.range(1..width + 1)
+                };
+                let number = rng.range(10usize.pow(digits as u32 - 1)..10usize.pow(digits as u32));
+                if rng.bool() {
+                    line.push_str(&format!("{number:<width$}"));
+                } else {
+                    line.push_str(&format!("{number:>width$}"));
+                }
+            }
+
+            let op = rng.choose(&['+', '*']);
+            lines[rows].push_str(&format!("{op:<width$}"));
+
+            if problem + 1 < problems {
+                lines.iter_mut().for_each(|l| l.push(' '));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn parser_part_one_never_panics() {
+        fuzz::assert_parser_total(&worksheet, |input| input.parse::<MathProblemSet<PartOne>>());
+    }
+
+    #[test]
+    fn parser_part_two_never_panics() {
+        fuzz::assert_parser_total(&worksheet, |input| input.parse::<MathProblemSet<PartTwo>>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
@@ -1,10 +1,61 @@
+use advent_of_code::template::visualize::{self, Frame};
+use thiserror::Error;
+
 advent_of_code::solution!(7);
 
-fn do_the_beams_thing(input: &str) -> (usize, Vec<Option<usize>>) {
+#[derive(Debug, Error)]
+enum ManifoldParsingError {
+    #[error("empty manifold")]
+    Empty,
+    #[error("unexpected input: {0}")]
+    UnexpectedInput(char),
+    #[error("row of width {found}, expected all rows to be {expected} wide")]
+    RaggedRows { expected: usize, found: usize },
+    #[error("splitter at the edge of row {row}")]
+    SplitterAtEdge { row: usize },
+}
+
+/// The rows of the manifold, checked to only contain the start, splitters and empty space.
+struct Manifold<'a>(Vec<&'a str>);
+
+impl<'a> TryFrom<&'a str> for Manifold<'a> {
+    type Error = ManifoldParsingError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let rows: Vec<&str> = input.lines().collect();
+        let width = rows.first().ok_or(ManifoldParsingError::Empty)?.len();
+
+        for (row, line) in rows.iter().enumerate() {
+            if let Some(invalid) = line.chars().find(|c| !matches!(c, 'S' | '^' | '.')) {
+                return Err(ManifoldParsingError::UnexpectedInput(invalid));
+            }
+            if line.len() != width {
+                return Err(ManifoldParsingError::RaggedRows {
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+            // a split beam continues on both sides of the splitter.
+            if line.starts_with('^') || line.ends_with('^') {
+                return Err(ManifoldParsingError::SplitterAtEdge { row });
+            }
+        }
+
+        Ok(Self(rows))
+    }
+}
+
+fn do_the_beams_thing(manifold: &Manifold) -> (usize, Vec<Option<usize>>) {
     let mut splits = 0;
-    let length = input.lines().next().unwrap().len();
+    let length = manifold.0[0].len();
     let mut beams: Vec<Option<usize>> = (0..length).map(|_| None).collect();
-    for line in input.lines() {
+
+    // rows processed so far, with beams drawn in.
+    let mut rendered: Vec<String> = vec![];
+
+    for (row, line) in manifold.0.iter().enumerate() {
+        let mut split_at = vec![];
+
         for (idx, char) in line.chars().enumerate().filter(|(_, c)| *c != '.') {
             match char {
                 'S' => {
@@ -16,6 +67,10 @@ fn do_the_beams_thing(input: &str) -> (usize, Vec<Option<usize>>) {
                         splits += 1;
                         beams[idx] = None;
 
+                        if visualize::ENABLED {
+                            split_at.push(idx);
+                        }
+
                         let left = beams[idx - 1].unwrap_or(0) + value;
                         beams[idx - 1] = Some(left);
 
@@ -23,28 +78,80 @@ fn do_the_beams_thing(input: &str) -> (usize, Vec<Option<usize>>) {
                         beams[idx + 1] = Some(right);
                     }
                 }
-                invalid => panic!("unexpected input: {invalid}"),
+                _ => {}
             }
         }
+
+        if visualize::ENABLED {
+            rendered.push(
+                line.chars()
+                    .enumerate()
+                    .map(|(idx, c)| match (c, beams[idx]) {
+                        ('.', Some(_)) => '|',
+                        (c, _) => c,
+                    })
+                    .collect(),
+            );
+
+            visualize::emit(|| {
+                Frame::new(
+                    rendered
+                        .iter()
+                        .map(String::as_str)
+                        .chain(manifold.0.iter().copied().skip(row + 1)),
+                )
+                .highlight_all(split_at.iter().map(|&col| (row, col)))
+                .caption(format!("splits: {splits}"))
+            });
+        }
     }
 
     (splits, beams)
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let (splits, _) = do_the_beams_thing(input);
+    let manifold = Manifold::try_from(input).ok()?;
+    let (splits, _) = do_the_beams_thing(&manifold);
     splits.into()
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let (_, beams) = do_the_beams_thing(input);
+    let manifold = Manifold::try_from(input).ok()?;
+    let (_, beams) = do_the_beams_thing(&manifold);
     beams.into_iter().flatten().sum::<usize>().into()
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn manifold(rng: &mut Rng) -> String {
+        let width = rng.range(3..20);
+        let start = rng.range(1..width - 1);
+
+        let mut rows = vec![format!(
+            "{}S{}",
+            ".".repeat(start),
+            ".".repeat(width - start - 1)
+        )];
+        for _ in 0..rng.range(1..20) {
+            let inner = rng.string_from(&['.', '.', '.', '^'], width - 2);
+            rows.push(format!(".{inner}."));
+        }
+
+        rows.join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        // solving as well, since a manifold borrows its input.
+        fuzz::assert_parser_total(&manifold, |input| {
+            Manifold::try_from(input).map(|manifold| do_the_beams_thing(&manifold))
+        });
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
@@ -177,8 +177,25 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn junction_boxes(rng: &mut Rng) -> String {
+        (0..rng.range(1..20))
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|_| rng.range(0..100_000));
+                format!("{x},{y},{z}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&junction_boxes, |input| input.parse::<Problem>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one_example(&advent_of_code::template::read_file("examples", DAY));
@@ -16,9 +16,7 @@ impl Point {
         let width = self.x.abs_diff(other.x) + 1;
         let height = self.y.abs_diff(other.y) + 1;
 
-        let res = width * height;
-
-        res
+        width * height
     }
 }
 
@@ -69,29 +67,43 @@ impl FromStr for Points {
 }
 
 impl Points {
-    fn area_of_largest_box(&self) -> usize {
+    /// `None` with less than two points, which cannot span a box.
+    fn area_of_largest_box(&self) -> Option<usize> {
         self.iter()
             .tuple_combinations()
             .map(|(a, b)| a.area_of_box(b))
             .max()
-            .unwrap()
     }
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
     let points: Points = input.parse().expect("can parse");
 
-    points.area_of_largest_box().into()
+    points.area_of_largest_box()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(_input: &str) -> Option<u64> {
     None
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn red_tiles(rng: &mut Rng) -> String {
+        (0..rng.range(2..20))
+            .map(|_| format!("{},{}", rng.range(0..100_000), rng.range(0..100_000)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&red_tiles, |input| input.parse::<Points>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
@@ -129,6 +129,7 @@ impl FromStr for SingleWiringSchematic {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 struct JoltageReqs(Vec<usize>);
 
@@ -172,6 +173,7 @@ impl FromStr for JoltageReqs {
 struct Machine {
     indicator_lights: IndicatorLights,
     button_wiring: Vec<SingleWiringSchematic>,
+    #[allow(dead_code)]
     joltage: JoltageReqs,
 }
 
@@ -330,14 +332,49 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(fewest)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(_input: &str) -> Option<u64> {
     None
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn machine(rng: &mut Rng) -> String {
+        let lights = rng.range(1..11);
+
+        let indicator = rng.string_from(&['.', '#'], lights);
+
+        let buttons = (0..rng.range(1..7))
+            .map(|_| {
+                let wiring = (0..lights)
+                    .filter(|_| rng.one_in(3))
+                    .map(|light| light.to_string())
+                    .collect::<Vec<_>>();
+                if wiring.is_empty() {
+                    format!("({})", rng.range(0..lights))
+                } else {
+                    format!("({})", wiring.join(","))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let joltage = (0..lights)
+            .map(|_| rng.range(0..300).to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("[{indicator}] {buttons} {{{joltage}}}")
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&machine, |line| line.parse::<Machine>());
+    }
+
     #[test]
     fn first() {
         let file = advent_of_code::template::read_file("examples", DAY);
@@ -345,7 +382,7 @@ mod tests {
         let machine: Machine = line.parse().expect("parses");
 
         let actual = machine.fewest_button_presses();
-        dbg!(&actual.1);
+        advent_of_code::debug!("button presses: {:?}", actual.1);
         assert_eq!(actual.0, 2);
     }
 
@@ -54,7 +54,7 @@ impl FromStr for DeviceId {
     type Err = DeviceParsingError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 3 || s.chars().any(|c| !c.is_alphabetic()) {
+        if s.len() != 3 || s.chars().any(|c| !c.is_ascii_alphabetic()) {
             return Err(DeviceParsingError::InvalidFormat);
         }
 
@@ -99,23 +99,20 @@ pub fn part_one(input: &str) -> Option<usize> {
         .lines()
         .map(|line| line.parse::<DeviceAttachment>())
         .collect::<Result<Vec<DeviceAttachment>, DeviceAttachmentParseError>>()
-        .expect("can parse");
+        .ok()?;
 
     let mut working_set = BinaryHeap::with_capacity(graph.len());
     working_set.push(DeviceId::start());
 
     let mut result = 0;
     while let Some(item) = working_set.pop() {
-        let entry = graph
-            .iter()
-            .find(|g| *g.device() == item)
-            .expect("entry is in graph");
+        let entry = graph.iter().find(|g| *g.device() == item)?;
 
         for addition in entry.attachments().iter() {
             if *addition == DeviceId::end() {
                 result += 1;
             } else {
-                // seen.push(addition);
+                advent_of_code::trace!("queueing {addition}");
                 working_set.push(*addition);
             }
         }
@@ -124,14 +121,33 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(_input: &str) -> Option<usize> {
     None
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::template::fuzz::{self, Rng};
+
     use super::*;
 
+    fn device_id(rng: &mut Rng) -> String {
+        rng.string_from(&['a', 'b', 'c', 'x', 'y', 'z'], 3)
+    }
+
+    fn attachment(rng: &mut Rng) -> String {
+        let attachments = (0..rng.range(1..5))
+            .map(|_| device_id(rng))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("{}: {attachments}", device_id(rng))
+    }
+
+    #[test]
+    fn parser_never_panics() {
+        fuzz::assert_parser_total(&attachment, |line| line.parse::<DeviceAttachment>());
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, compare, download, example, read, refresh, scaffold, solve, stars, team, time, watch,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,7 +9,8 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::{example, refresh, scaffold, solve};
+    use advent_of_code::template::{Day, DayFilter, InputSource, Status, log, visualize};
     use std::process;
 
     pub enum AppArguments {
@@ -20,60 +23,262 @@ mod args {
         Scaffold {
             day: Day,
             download: bool,
-            overwrite: bool,
+            options: scaffold::Options,
         },
         Solve {
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            options: solve::Options,
         },
         All {
+            filter: DayFilter,
             release: bool,
+            jobs: usize,
+        },
+        Team {
+            day: Day,
+            release: bool,
+            time: bool,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            filter: DayFilter,
             store: bool,
+            mem: bool,
+            perf: bool,
+            history: bool,
+            report: bool,
+            log_scale: bool,
+        },
+        WatchDay {
+            day: Day,
+            release: bool,
+        },
+        Stars,
+        Example {
+            day: Day,
+            options: example::Options,
+        },
+        Refresh {
+            day: Day,
+            options: refresh::Options,
+        },
+        Compare {
+            a: String,
+            b: String,
+            filter: DayFilter,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Parse the day and part filters shared by bulk commands.
+    /// NOTE: the day selection is an optional free argument, parse this after all other options.
+    fn parse_filter(
+        args: &mut pico_args::Arguments,
+    ) -> Result<DayFilter, Box<dyn std::error::Error>> {
+        let filter = parse_filter_options(args)?;
+
+        Ok(DayFilter {
+            days: args.opt_free_from_str()?,
+            ..filter
+        })
+    }
+
+    /// Parse the options of the filters, without the day selection.
+    fn parse_filter_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<DayFilter, Box<dyn std::error::Error>> {
+        let part = args.opt_value_from_str("--part")?;
+        if part.is_some_and(|part| part != 1 && part != 2) {
+            return Err("`--part` expects 1 or 2".into());
+        }
+
+        let status = match (args.contains("--solved"), args.contains("--unsolved")) {
+            (true, true) => return Err("`--solved` and `--unsolved` cannot be combined".into()),
+            (true, false) => Some(Status::Solved),
+            (false, true) => Some(Status::Unsolved),
+            (false, false) => None,
+        };
+
+        Ok(DayFilter {
+            days: None,
+            part,
+            status,
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
+            Some("all") => {
+                let release = args.contains("--release");
+                let jobs = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+
+                AppArguments::All {
+                    filter: parse_filter(&mut args)?,
+                    release,
+                    jobs,
+                }
+            }
+            Some("team") => AppArguments::Team {
+                day: args.free_from_str()?,
                 release: args.contains("--release"),
+                time: args.contains("--time"),
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let mem = args.contains("--mem");
+                let perf = args.contains("--perf");
+                let history = args.contains("--history");
+                let report = args.contains("--report");
+                let log_scale = args.contains("--log-scale");
+
+                if history && report {
+                    return Err("`--history` cannot be combined with `--report`".into());
+                }
+                if log_scale && !report {
+                    return Err("`--log-scale` can only be used with `--report`".into());
+                }
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    filter: parse_filter(&mut args)?,
                     store,
+                    mem,
+                    perf,
+                    history,
+                    report,
+                    log_scale,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
+            Some("watch-day") => AppArguments::WatchDay {
                 day: args.free_from_str()?,
+                release: args.contains("--release"),
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("stars") => AppArguments::Stars,
+            Some("example") => {
+                let part = args.opt_value_from_str("--part")?.unwrap_or(1);
+                if part != 1 && part != 2 {
+                    return Err("`--part` expects 1 or 2".into());
+                }
+                let options = example::Options {
+                    part,
+                    block: args.opt_value_from_str("--block")?,
+                    list: args.contains("--list"),
+                    overwrite: args.contains("--overwrite"),
+                };
+
+                AppArguments::Example {
+                    day: args.free_from_str()?,
+                    options,
+                }
+            }
+            Some("refresh") => {
+                let options = refresh::Options {
+                    offline: args.contains("--offline"),
+                    overwrite: args.contains("--overwrite"),
+                };
+
+                AppArguments::Refresh {
+                    day: args.free_from_str()?,
+                    options,
+                }
+            }
+            Some("compare") => {
+                let filter = parse_filter_options(&mut args)?;
+                let a = args.free_from_str()?;
+                let b = args.free_from_str()?;
+
+                AppArguments::Compare {
+                    a,
+                    b,
+                    filter: DayFilter {
+                        days: args.opt_free_from_str()?,
+                        ..filter
+                    },
+                }
+            }
+            Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
             },
+            Some("scaffold") => {
+                let download = args.contains("--download");
+                let options = scaffold::Options {
+                    overwrite: args.contains("--overwrite"),
+                    dry_run: args.contains("--dry-run"),
+                    template: args
+                        .opt_value_from_str("--template")?
+                        .unwrap_or_else(|| "default".into()),
+                };
+
+                AppArguments::Scaffold {
+                    day: args.free_from_str()?,
+                    download,
+                    options,
+                }
+            }
+            Some("solve") => {
+                let day = args.free_from_str()?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let mem = args.contains("--mem");
+                let profile = args.contains("--profile");
+                if dhat && (mem || profile) {
+                    return Err("`--dhat` cannot be combined with `--mem` or `--profile`".into());
+                }
+                let visualize = visualize::Options {
+                    play: args.contains("--visualize"),
+                    dump: args.contains("--dump-frames"),
+                    export: args.opt_value_from_str("--export")?,
+                    fps: args
+                        .opt_value_from_str("--fps")?
+                        .unwrap_or(visualize::Options::default().fps),
+                };
+
+                let mut verbose_count = 0;
+                for (flag, count) in [("-v", 1), ("-vv", 2), ("-vvv", 3)] {
+                    while args.contains(flag) {
+                        verbose_count += count;
+                    }
+                }
+                let verbosity = log::Verbosity::new(
+                    verbose_count,
+                    args.values_from_str::<_, log::Directive>("--verbose")?,
+                );
+
+                let input_path: Option<String> = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+
+                // NOTE: the example number is an optional free argument, parse it after all other options.
+                let input = match (input_path, example) {
+                    (Some(_), true) => {
+                        return Err("`--input` and `--example` cannot be combined".into());
+                    }
+                    (Some(path), false) => InputSource::from_args(&["--input", &path])?,
+                    (None, true) => InputSource::Example(args.opt_free_from_str()?),
+                    (None, false) => InputSource::Default,
+                };
+
+                AppArguments::Solve {
+                    day,
+                    options: solve::Options {
+                        release,
+                        dhat,
+                        mem,
+                        profile,
+                        submit,
+                        input,
+                        visualize,
+                        verbosity,
+                    },
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -102,31 +307,56 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                filter,
+                release,
+                jobs,
+            } => all::handle(&filter, release, jobs),
+            AppArguments::Team { day, release, time } => team::handle(day, release, time),
+            AppArguments::Time {
+                filter,
+                history: true,
+                ..
+            } => time::history(&filter),
+            AppArguments::Time {
+                filter,
+                report: true,
+                log_scale,
+                ..
+            } => time::report(&filter, log_scale),
+            AppArguments::Time {
+                filter,
+                all,
+                store,
+                mem,
+                perf,
+                ..
+            } => time::handle(&filter, all, store, mem, perf),
+            AppArguments::WatchDay { day, release } => watch::handle(day, release),
+            AppArguments::Stars => stars::handle(),
+            AppArguments::Example { day, options } => example::handle(day, &options),
+            AppArguments::Refresh { day, options } => refresh::handle(day, &options),
+            AppArguments::Compare { a, b, filter } => compare::handle(&a, &b, &filter),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
-                overwrite,
+                options,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
+                scaffold::handle(day, &options);
+                if download && options.dry_run {
+                    println!("Dry run, skipping the download.");
+                } else if download {
                     download::handle(day);
                 }
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+            AppArguments::Solve { day, options } => solve::handle(day, &options),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
+                        scaffold::handle(day, &scaffold::Options::default());
                         download::handle(day);
                         read::handle(day)
                     }
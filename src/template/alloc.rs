@@ -0,0 +1,218 @@
+/// Allocation statistics of solution parts.
+///
+/// Solution binaries built with the `alloc-stats` feature install [`CountingAlloc`] as their global allocator.
+/// The runner then measures the first run of each part: peak heap usage, number of allocations and bytes allocated.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// `true` if allocations are counted in this build.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+/// A global allocator that forwards to [`System`] and counts what passes through it.
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: all calls are forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // a reallocation counts as one allocation of the new size that frees the old one.
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while measuring, see [`measure`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The highest heap usage above the usage at the start of the measurement.
+    pub peak_bytes: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl AllocStats {
+    /// The exact stats for the runner to read back, e.g. `peak=2048 allocations=17 allocated=100`.
+    /// [`Display`] rounds the byte counts, so it is only meant for people.
+    #[must_use]
+    pub fn to_record(&self) -> String {
+        format!(
+            "peak={} allocations={} allocated={}",
+            self.peak_bytes, self.allocations, self.allocated_bytes
+        )
+    }
+
+    /// Parse stats as printed by [`AllocStats::to_record`]. Anything after the record is ignored.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut fields = s.split_whitespace().map_while(|f| f.split_once('='));
+        let mut field = |key: &str| {
+            fields
+                .next()
+                .filter(|(k, _)| *k == key)
+                .and_then(|(_, v)| v.parse().ok())
+        };
+
+        Some(Self {
+            peak_bytes: field("peak")?,
+            allocations: field("allocations")?,
+            allocated_bytes: field("allocated")?,
+        })
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocations, {} allocated",
+            format_bytes(self.peak_bytes),
+            self.allocations,
+            format_bytes(self.allocated_bytes)
+        )
+    }
+}
+
+const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+/// Format a byte count with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Parse a byte count formatted by [`format_bytes`]. Precision is limited to what was printed.
+#[must_use]
+pub fn parse_bytes(s: &str) -> Option<u64> {
+    let (value, unit) = s.trim().split_once(' ')?;
+    let value: f64 = value.parse().ok()?;
+    let exponent = UNITS.iter().position(|u| *u == unit)?;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_possible_wrap
+    )]
+    Some((value * 1024_f64.powi(exponent as i32)).round() as u64)
+}
+
+/// Run `func`, returning its result and the allocations it made.
+/// Without [`CountingAlloc`] installed as the global allocator, all stats are zero.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+    };
+
+    (result, stats)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AllocStats, format_bytes, parse_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn parses_bytes() {
+        assert_eq!(parse_bytes("512 B"), Some(512));
+        assert_eq!(parse_bytes("1.5 KiB"), Some(1536));
+        assert_eq!(parse_bytes("2.0 GiB"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes("2.0 PiB"), None);
+    }
+
+    #[test]
+    fn parses_recorded_stats() {
+        let stats = AllocStats {
+            peak_bytes: 1_234_567,
+            allocations: 17,
+            allocated_bytes: 100,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "1.2 MiB peak, 17 allocations, 100 B allocated"
+        );
+        assert_eq!(
+            stats.to_record(),
+            "peak=1234567 allocations=17 allocated=100"
+        );
+        assert_eq!(AllocStats::parse(&stats.to_record()), Some(stats));
+        assert_eq!(
+            AllocStats::parse(&format!("{} ({stats})", stats.to_record())),
+            Some(stats)
+        );
+        assert_eq!(AllocStats::parse(&stats.to_string()), None);
+        assert_eq!(AllocStats::parse("allocations=17"), None);
+    }
+}
@@ -1,5 +1,24 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::num::NonZeroUsize;
+use std::thread;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::DayFilter;
+use crate::template::run_multi::{RunOptions, run_multi};
+use crate::template::submissions;
+
+/// Run all days that pass `filter`, `jobs` at a time. With `jobs` set to 0, one day per available CPU core is run.
+pub fn handle(filter: &DayFilter, is_release: bool, jobs: usize) {
+    let jobs = match jobs {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        jobs => jobs,
+    };
+
+    run_multi(
+        &filter.select(&submissions::progress()),
+        RunOptions {
+            is_release,
+            jobs,
+            part: filter.part,
+            ..RunOptions::default()
+        },
+    );
 }
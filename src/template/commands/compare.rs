@@ -0,0 +1,253 @@
+/// Compare two sets of stored benchmarks per day and part.
+///
+/// Each side is a timings file, a machine profile or a git revision, optionally with a profile as `<rev>:<profile>`.
+use std::path::Path;
+use std::process::{self, Command};
+
+use crate::template::history::machine_id;
+use crate::template::submissions::{self, Progress};
+use crate::template::timings::{PartTiming, Timing, Timings, format_nanos, profile_path};
+use crate::template::{ANSI_BOLD, ANSI_RESET, DayFilter, all_days};
+
+/// The timings stored at git revision `rev` for `profile`, or in the legacy timings file at that revision.
+fn read_revision(rev: &str, profile: &str) -> Option<Timings> {
+    let profile_file = profile_path(profile);
+    [profile_file.trim_start_matches("./"), "data/timings.json"]
+        .iter()
+        .find_map(|path| {
+            let output = Command::new("git")
+                .args(["show", &format!("{rev}:{path}")])
+                .output()
+                .ok()
+                .filter(|o| o.status.success())?;
+            Timings::try_from(String::from_utf8_lossy(&output.stdout).into_owned()).ok()
+        })
+}
+
+/// Resolve one side of the comparison.
+fn resolve(spec: &str) -> Result<Timings, String> {
+    if Path::new(spec).is_file() {
+        return Timings::read_path(spec).ok_or(format!("\"{spec}\" is not a timings file."));
+    }
+
+    if let Some(timings) = Timings::read_profile(spec) {
+        return Ok(timings);
+    }
+
+    let (rev, profile) = match spec.split_once(':') {
+        Some((rev, profile)) => (rev, profile.to_string()),
+        None => (spec, machine_id()),
+    };
+
+    read_revision(rev, &profile).ok_or(format!(
+        "`{spec}` is neither a timings file, a profile in \"data/timings\" nor a git revision with stored timings."
+    ))
+}
+
+/// How `b` compares to `a`, e.g. `▼ 2.00× faster`.
+fn format_ratio(a: f64, b: f64) -> String {
+    let ratio = b / a;
+    match ratio {
+        r if (r - 1.0).abs() < 0.005 => "= 1.00×".into(),
+        r if r < 1.0 => format!("▼ {:.2}× faster", 1.0 / r),
+        r => format!("▲ {r:.2}× slower"),
+    }
+}
+
+fn part_of(timing: Option<&Timing>, part: u8) -> Option<PartTiming> {
+    timing.and_then(|t| match part {
+        1 => t.part_1,
+        _ => t.part_2,
+    })
+}
+
+/// A cell of a side: the time of the part, or why it is missing.
+fn cell(timing: Option<&Timing>, part: u8) -> String {
+    match (part_of(timing, part), timing) {
+        (Some(time), _) => time.to_string(),
+        (
+            None,
+            Some(Timing {
+                aborted: Some(reason),
+                ..
+            }),
+        ) => reason.clone(),
+        _ => "-".into(),
+    }
+}
+
+/// The rows of the comparison, plus the totals over the parts present on both sides.
+/// The status of days in `filter` is judged by `progress`.
+fn compare(
+    a: &Timings,
+    b: &Timings,
+    filter: &DayFilter,
+    progress: &Progress,
+) -> (Vec<[String; 5]>, Option<(f64, f64)>) {
+    let mut rows = vec![];
+    let mut totals: Option<(f64, f64)> = None;
+    let selected = filter.select(progress);
+
+    for day in all_days().filter(|day| selected.contains(day)) {
+        let timing_a = a.data.iter().find(|t| t.day == day);
+        let timing_b = b.data.iter().find(|t| t.day == day);
+
+        if timing_a.is_none() && timing_b.is_none() {
+            continue;
+        }
+
+        for part in (1..=2).filter(|p| filter.part.is_none_or(|selected| selected == *p)) {
+            let ratio = match (part_of(timing_a, part), part_of(timing_b, part)) {
+                (Some(time_a), Some(time_b)) => {
+                    let (total_a, total_b) = totals.unwrap_or_default();
+                    totals = Some((total_a + time_a.nanos, total_b + time_b.nanos));
+                    format_ratio(time_a.nanos, time_b.nanos)
+                }
+                _ => String::new(),
+            };
+
+            rows.push([
+                day.to_string(),
+                part.to_string(),
+                cell(timing_a, part),
+                cell(timing_b, part),
+                ratio,
+            ]);
+        }
+    }
+
+    (rows, totals)
+}
+
+pub fn handle(a: &str, b: &str, filter: &DayFilter) {
+    let (timings_a, timings_b) = match (resolve(a), resolve(b)) {
+        (Ok(timings_a), Ok(timings_b)) => (timings_a, timings_b),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let (rows, totals) = compare(&timings_a, &timings_b, filter, &submissions::progress());
+
+    if rows.is_empty() {
+        println!("No benchmarks to compare.");
+        return;
+    }
+
+    let width_a = rows
+        .iter()
+        .map(|r| r[2].chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(a.len());
+    let width_b = rows
+        .iter()
+        .map(|r| r[3].chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(b.len());
+
+    println!(
+        "{ANSI_BOLD}{:<3}  {:<4}  {a:>width_a$}  {b:>width_b$}  Ratio{ANSI_RESET}",
+        "Day", "Part"
+    );
+    for [day, part, time_a, time_b, ratio] in &rows {
+        let line = format!("{day:<3}  {part:<4}  {time_a:>width_a$}  {time_b:>width_b$}  {ratio}");
+        println!("{}", line.trim_end());
+    }
+
+    if let Some((total_a, total_b)) = totals {
+        println!();
+        println!(
+            "{:<9}  {:>width_a$}  {:>width_b$}  {}",
+            "Total",
+            format_nanos(total_a),
+            format_nanos(total_b),
+            format_ratio(total_a, total_b)
+        );
+        println!("Totals only include parts benched on both sides.");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{compare, format_ratio};
+    use crate::day;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::template::{Day, DayFilter, Status};
+
+    fn timing(day: Day, part_1: f64, part_2: Option<f64>) -> Timing {
+        let part = |nanos: f64| PartTiming {
+            nanos,
+            samples: Some(100),
+            median_nanos: None,
+        };
+        Timing {
+            day,
+            part_1: Some(part(part_1)),
+            part_2: part_2.map(part),
+            total_nanos: part_1 + part_2.unwrap_or_default(),
+            parse: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            aborted: None,
+        }
+    }
+
+    #[test]
+    fn formats_ratios() {
+        assert_eq!(format_ratio(100.0, 50.0), "▼ 2.00× faster");
+        assert_eq!(format_ratio(100.0, 150.0), "▲ 1.50× slower");
+        assert_eq!(format_ratio(100.0, 100.2), "= 1.00×");
+    }
+
+    #[test]
+    fn compares_timings() {
+        let a = Timings {
+            data: vec![
+                timing(day!(1), 1_000.0, Some(4_000_000.0)),
+                timing(day!(2), 2_000.0, None),
+            ],
+        };
+        let b = Timings {
+            data: vec![timing(day!(1), 2_000.0, Some(2_000_000.0))],
+        };
+
+        let (rows, totals) = compare(&a, &b, &DayFilter::default(), &BTreeMap::new());
+
+        assert_eq!(
+            rows.iter().map(|r| r.join(" | ")).collect::<Vec<_>>(),
+            [
+                "01 | 1 | 1.0µs | 2.0µs | ▲ 2.00× slower",
+                "01 | 2 | 4.0ms | 2.0ms | ▼ 2.00× faster",
+                "02 | 1 | 2.0µs | - | ",
+                "02 | 2 | - | - | ",
+            ]
+        );
+        assert_eq!(totals, Some((4_001_000.0, 2_002_000.0)));
+
+        let filter = DayFilter {
+            part: Some(2),
+            ..DayFilter::default()
+        };
+        assert_eq!(compare(&a, &b, &filter, &BTreeMap::new()).0.len(), 2);
+
+        // only day 2 is solved.
+        let filter = DayFilter {
+            status: Some(Status::Solved),
+            ..DayFilter::default()
+        };
+        let progress = BTreeMap::from([(day!(2), [Some(None), Some(None)])]);
+        let (rows, totals) = compare(&a, &b, &filter, &progress);
+        assert_eq!(
+            rows.iter().map(|r| r[0].as_str()).collect::<Vec<_>>(),
+            ["02", "02"]
+        );
+        assert_eq!(totals, None);
+    }
+}
@@ -0,0 +1,384 @@
+/// Extract the example of a day from its downloaded description into `data/examples`, and its answer into the tests.
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use crate::template::puzzle::{CodeBlock, Puzzle};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub part: u8,
+    /// Use this block (counted from 1 over the whole description) instead of guessing.
+    pub block: Option<usize>,
+    /// Only list the code blocks of the description.
+    pub list: bool,
+    /// Replace an existing example file and expectation.
+    pub overwrite: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            part: 1,
+            block: None,
+            list: false,
+            overwrite: false,
+        }
+    }
+}
+
+/// The example file of `part`. Part two only gets its own file if its example differs.
+pub(crate) fn example_path(day: Day, part: u8) -> String {
+    match part {
+        1 => format!("data/examples/{day}.txt"),
+        _ => format!("data/examples/{day}-{part}.txt"),
+    }
+}
+
+pub(crate) fn test_name(part: u8) -> &'static str {
+    match part {
+        1 => "test_part_one",
+        _ => "test_part_two",
+    }
+}
+
+/// The expected value of a test, e.g. `Some(11)` or `Some("CMZ".to_string())`.
+fn literal(answer: &str) -> String {
+    if answer.parse::<i128>().is_ok() {
+        format!("Some({answer})")
+    } else {
+        format!("Some({answer:?}.to_string())")
+    }
+}
+
+/// The start of the function `name` and its source, up to the next function.
+fn function<'a>(source: &'a str, name: &str) -> Option<(usize, &'a str)> {
+    let start = source.find(&format!("fn {name}("))?;
+    let body = &source[start..];
+    let end = body[1..].find("fn ").map_or(body.len(), |end| end + 1);
+    Some((start, &body[..end]))
+}
+
+/// Byte range of the expected value in the `assert_eq!(result, expected)` of `test`.
+fn locate_expectation(source: &str, test: &str) -> Result<(usize, usize), String> {
+    let (test_start, body) = function(source, test).ok_or(format!("no test `{test}` found."))?;
+
+    let args_start = body
+        .find("assert_eq!(")
+        .ok_or(format!("no `assert_eq!` found in `{test}`."))?
+        + "assert_eq!(".len();
+
+    // split the arguments at the top-level comma, up to the closing parenthesis.
+    let mut depth = 0;
+    let mut comma = None;
+    let mut close = None;
+    for (index, c) in body[args_start..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth > 0 => depth -= 1,
+            ')' => {
+                close = Some(index);
+                break;
+            }
+            ',' if depth == 0 && comma.is_none() => comma = Some(index),
+            _ => {}
+        }
+    }
+
+    let (Some(comma), Some(close)) = (comma, close) else {
+        return Err(format!("could not read the `assert_eq!` of `{test}`."));
+    };
+
+    let expected = &body[args_start + comma + 1..args_start + close];
+    let trimmed = expected.trim().trim_end_matches(',').trim_end();
+    let start =
+        test_start + args_start + comma + 1 + (expected.len() - expected.trim_start().len());
+
+    Ok((start, start + trimmed.len()))
+}
+
+/// The outcome of setting an expectation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Expectation {
+    /// The source with the new expectation.
+    Updated(String),
+    /// The test already expects the answer.
+    Unchanged,
+    /// The test expects something else, which is only replaced with `overwrite`.
+    Kept(String),
+}
+
+/// Set the expected value of `test` in `source` to `answer`. Expectations other than `None` are only
+/// replaced with `overwrite`.
+pub(crate) fn set_expectation(
+    source: &str,
+    test: &str,
+    answer: &str,
+    overwrite: bool,
+) -> Result<Expectation, String> {
+    let (start, end) = locate_expectation(source, test)?;
+    let current = &source[start..end];
+    let expected = literal(answer);
+
+    if current == expected {
+        return Ok(Expectation::Unchanged);
+    }
+    if current != "None" && !overwrite {
+        return Ok(Expectation::Kept(current.to_string()));
+    }
+
+    let mut updated = source.to_string();
+    updated.replace_range(start..end, &expected);
+    Ok(Expectation::Updated(updated))
+}
+
+fn preview(block: &CodeBlock) -> String {
+    let first = block.content.lines().next().unwrap_or_default();
+    let lines = block.content.lines().count();
+    format!(
+        "part {}, {lines} line{}: {first}",
+        block.part,
+        if lines == 1 { "" } else { "s" }
+    )
+}
+
+fn list(puzzle: &Puzzle, part: u8) {
+    let picked = puzzle.example(part);
+
+    for (index, block) in puzzle.blocks.iter().enumerate() {
+        let marker = if picked.is_some_and(|p| std::ptr::eq(p, block)) {
+            format!(" {ANSI_BOLD}← example of part {part}{ANSI_RESET}")
+        } else {
+            String::new()
+        };
+        println!("[{}] {}{marker}", index + 1, preview(block));
+    }
+}
+
+/// Write the example of `part`. Returns whether the tests of this part read a separate example file.
+fn write_example(day: Day, puzzle: &Puzzle, options: &Options) -> Result<bool, String> {
+    let block = match options.block {
+        Some(index) => puzzle
+            .blocks
+            .get(index.wrapping_sub(1))
+            .ok_or(format!("there is no block {index}, see `--list`."))?,
+        None => match puzzle.example(options.part) {
+            Some(block) => block,
+            None if options.part == 2 => {
+                println!(
+                    "{ANSI_ITALIC}Part two has no example of its own, keeping the example of part one.{ANSI_RESET}"
+                );
+                return Ok(false);
+            }
+            None => return Err("the description has no code blocks.".into()),
+        },
+    };
+
+    // part two mostly reuses the example of part one.
+    if options.part == 2
+        && fs::read_to_string(example_path(day, 1)).is_ok_and(|c| c == block.content)
+    {
+        println!("Part two uses the example of part one.");
+        return Ok(false);
+    }
+
+    let path = example_path(day, options.part);
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+
+    if existing == block.content {
+        println!("\"{path}\" already holds the example.");
+    } else if !existing.trim().is_empty() && !options.overwrite {
+        println!(
+            "Keeping \"{path}\", which differs from the example. Use `--overwrite` to replace it."
+        );
+    } else {
+        fs::write(&path, &block.content).map_err(|e| format!("failed to write \"{path}\": {e}"))?;
+        println!("Wrote example ({}) to \"{path}\".", preview(block));
+    }
+
+    Ok(options.part == 2)
+}
+
+/// Point the test of part two at its own example file.
+fn use_part_example(source: &str) -> Option<String> {
+    let (test_start, body) = function(source, "test_part_two")?;
+    let call = "read_file(\"examples\", DAY)";
+    let offset = body.find(call)?;
+
+    let mut updated = source.to_string();
+    updated.replace_range(
+        test_start + offset..test_start + offset + call.len(),
+        "read_file_part(\"examples\", DAY, 2)",
+    );
+    Some(updated)
+}
+
+/// Write the expected answer of `part` into its test.
+pub(crate) fn update_test(
+    day: Day,
+    part: u8,
+    answer: &str,
+    own_example: bool,
+    overwrite: bool,
+) -> Result<(), String> {
+    let path = get_path_for_bin(day);
+    let source =
+        fs::read_to_string(&path).map_err(|e| format!("failed to read \"{path}\": {e}"))?;
+
+    let test = test_name(part);
+    let expected = literal(answer);
+
+    let mut updated = match set_expectation(&source, test, answer, overwrite)? {
+        Expectation::Updated(updated) => {
+            println!("Set the expected answer of `{test}` to {expected}.");
+            Some(updated)
+        }
+        Expectation::Unchanged => {
+            println!("`{test}` already expects {expected}.");
+            None
+        }
+        Expectation::Kept(current) => {
+            println!(
+                "Keeping the expectation {current} of `{test}`. Use `--overwrite` to set it to {expected}."
+            );
+            None
+        }
+    };
+
+    if own_example && let Some(with_part) = use_part_example(updated.as_deref().unwrap_or(&source))
+    {
+        println!("Pointed `{test}` at its own example file.");
+        updated = Some(with_part);
+    }
+
+    match updated {
+        Some(updated) => {
+            fs::write(&path, updated).map_err(|e| format!("failed to write \"{path}\": {e}"))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Write the example of `options.part` and its expected answer into the test.
+pub(crate) fn extract(day: Day, puzzle: &Puzzle, options: &Options) -> Result<(), String> {
+    let own_example = write_example(day, puzzle, options)
+        .map_err(|e| format!("Failed to extract the example: {e}"))?;
+
+    match puzzle.expected_answer(options.part) {
+        Some(_) if !Path::new(&get_path_for_bin(day)).exists() => {
+            println!("Day {day} has not been scaffolded yet, skipping the expected answer.");
+        }
+        Some(answer) => update_test(day, options.part, answer, own_example, options.overwrite)
+            .map_err(|e| format!("Failed to update the test: {e}"))?,
+        None => println!(
+            "No expected answer found, set it in `{}` yourself.",
+            test_name(options.part)
+        ),
+    }
+
+    Ok(())
+}
+
+pub fn handle(day: Day, options: &Options) {
+    let Some(puzzle) = Puzzle::read(day) else {
+        eprintln!("No description of day {day} found, download it with `cargo download {day}`.");
+        process::exit(1);
+    };
+
+    if options.list {
+        list(&puzzle, options.part);
+        return;
+    }
+
+    if options.part == 2 && !puzzle.has_part_two {
+        eprintln!(
+            "The description of day {day} has no part two yet, download it again once part one is solved."
+        );
+        process::exit(1);
+    }
+
+    if let Err(e) = extract(day, &puzzle, options) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Expectation, literal, set_expectation, use_part_example};
+
+    const SOURCE: &str = r#"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(
+            result,
+            Some(vec![(1, 2)].len()),
+        );
+    }
+}
+"#;
+
+    #[test]
+    fn formats_literals() {
+        assert_eq!(literal("11"), "Some(11)");
+        assert_eq!(literal("-3"), "Some(-3)");
+        assert_eq!(literal("CMZ"), "Some(\"CMZ\".to_string())");
+    }
+
+    #[test]
+    fn sets_expectations() {
+        let Ok(Expectation::Updated(updated)) =
+            set_expectation(SOURCE, "test_part_one", "11", false)
+        else {
+            panic!("expected the test to be updated");
+        };
+        assert!(updated.contains("assert_eq!(result, Some(11));"));
+        assert!(updated.contains("Some(vec![(1, 2)].len()),"));
+
+        // existing expectations are kept unless overwritten.
+        assert_eq!(
+            set_expectation(SOURCE, "test_part_two", "31", false),
+            Ok(Expectation::Kept("Some(vec![(1, 2)].len())".into()))
+        );
+        let Ok(Expectation::Updated(updated)) =
+            set_expectation(SOURCE, "test_part_two", "31", true)
+        else {
+            panic!("expected the test to be overwritten");
+        };
+        assert_eq!(
+            set_expectation(&updated, "test_part_two", "31", false),
+            Ok(Expectation::Unchanged)
+        );
+        assert!(updated.contains("            Some(31),\n        );"));
+
+        assert!(set_expectation(SOURCE, "test_parse", "1", false).is_err());
+    }
+
+    #[test]
+    fn uses_part_examples() {
+        let updated = use_part_example(SOURCE).unwrap();
+        assert!(
+            updated.contains("part_one(&advent_of_code::template::read_file(\"examples\", DAY))")
+        );
+        assert!(
+            updated.contains(
+                "part_two(&advent_of_code::template::read_file_part(\"examples\", DAY, 2))"
+            )
+        );
+    }
+}
@@ -1,6 +1,12 @@
 pub mod all;
+pub mod compare;
 pub mod download;
+pub mod example;
 pub mod read;
+pub mod refresh;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod team;
 pub mod time;
+pub mod watch;
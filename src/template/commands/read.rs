@@ -1,17 +1,294 @@
-use std::process;
+/// Render the stored description of a day in the terminal, downloading it first if it is missing.
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::process::{self, Command, Stdio};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const ANSI_HIGHLIGHT: &str = "\x1b[1;33m";
+
+/// Marks the lines of part two.
+const PART_TWO_GUTTER: &str = "┃";
+
+/// The heading of a line like `\--- Day 1: Historian Hysteria ---` or `# Title`.
+fn heading(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        return Some(line.trim_start_matches('#').trim());
+    }
+
+    let line = line.trim_start_matches('\\');
+    (line.len() > 8 && line.starts_with("--- ") && line.ends_with(" ---")).then_some(line)
+}
+
+/// Whether `line` underlines the heading before it.
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '-' || c == '=')
+}
+
+/// Render emphasis, code spans, links and escapes of a line.
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut emphasis = false;
+    let mut chars = text.char_indices().peekable();
+
+    let style = |emphasis: bool| if emphasis { ANSI_BOLD } else { "" };
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '*' | '_' if c == '*' || !is_inside_word(text, index) => {
+                while chars.next_if(|(_, next)| *next == c).is_some() {}
+                emphasis = !emphasis;
+                out.push_str(if emphasis { ANSI_BOLD } else { ANSI_RESET });
+            }
+            '`' => match text[index + 1..].find('`') {
+                Some(end) => {
+                    let code = &text[index + 1..index + 1 + end];
+                    let inner = code.trim_matches('*');
+                    let bold = emphasis || inner.len() < code.len();
+                    out.push_str(&format!(
+                        "{}{ANSI_CODE}{inner}{ANSI_RESET}{}",
+                        style(bold),
+                        style(emphasis)
+                    ));
+                    while chars
+                        .next_if(|(next, _)| *next <= index + 1 + end)
+                        .is_some()
+                    {}
+                }
+                None => out.push(c),
+            },
+            '[' => match link(&text[index..]) {
+                Some((label, length)) => {
+                    out.push_str(&format!(
+                        "{ANSI_UNDERLINE}{}{ANSI_RESET}{}",
+                        inline(label),
+                        style(emphasis)
+                    ));
+                    while chars.next_if(|(next, _)| *next < index + length).is_some() {}
+                }
+                None => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+
+    if emphasis {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// An underscore inside a word like `snake_case` is not emphasis.
+fn is_inside_word(text: &str, index: usize) -> bool {
+    let before = text[..index].chars().next_back();
+    let after = text[index + 1..].chars().next();
+    before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric)
+}
+
+/// The label and length of a link like `[label](url)` at the start of `text`.
+fn link(text: &str) -> Option<(&str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = text[label_end..].find(')')? + label_end;
+    Some((&text[1..label_end], url_end + 1))
+}
+
+/// Render the markdown of a description for the terminal.
+pub(crate) fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut is_code = false;
+    let mut is_part_two = false;
+    let mut after_heading = false;
+
+    for line in markdown.lines() {
+        let gutter = if is_part_two {
+            format!("{ANSI_HIGHLIGHT}{PART_TWO_GUTTER}{ANSI_RESET} ")
+        } else {
+            String::new()
+        };
+
+        if line.trim_start().starts_with("```") {
+            is_code = !is_code;
+            continue;
+        }
+
+        if is_code {
+            out.push_str(&format!("{gutter}    {ANSI_CODE}{line}{ANSI_RESET}\n"));
+            continue;
+        }
+
+        if after_heading && is_underline(line) {
+            after_heading = false;
+            continue;
+        }
+        after_heading = false;
+
+        if let Some(title) = heading(line) {
+            after_heading = true;
+
+            if title.contains("Part Two") {
+                is_part_two = true;
+                out.push_str(&format!(
+                    "{ANSI_HIGHLIGHT}{PART_TWO_GUTTER} {title}{ANSI_RESET}\n"
+                ));
+            } else {
+                out.push_str(&format!("{gutter}{ANSI_BOLD}{title}{ANSI_RESET}\n"));
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let rendered = match trimmed.strip_prefix("* ").or(trimmed.strip_prefix("- ")) {
+            Some(item) => format!(
+                "{}  • {}",
+                &line[..line.len() - trimmed.len()],
+                inline(item)
+            ),
+            None => inline(line),
+        };
+
+        if rendered.is_empty() {
+            out.push_str(gutter.trim_end());
+            out.push('\n');
+        } else {
+            out.push_str(&format!("{gutter}{rendered}\n"));
+        }
+    }
+
+    out
+}
+
+/// Show `text` in `$PAGER`, or `less`, when writing to a terminal.
+fn page(text: &str) {
+    if !io::stdout().is_terminal() {
+        print!("{text}");
+        return;
+    }
+
+    let pager = env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| "less -RFX".into());
+    let mut args = pager.split_whitespace();
+    let program = args.next().unwrap_or("less");
+
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .spawn();
+
+    match child {
+        Ok(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                // the pager may be quit before reading everything.
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+        }
+        Err(_) => print!("{text}"),
+    }
+}
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    let path = aoc_cli::get_puzzle_path(day);
+
+    if !Path::new(&path).exists() {
+        println!(
+            "{ANSI_ITALIC}No description of day {day} stored yet, downloading it.{ANSI_RESET}"
         );
-        process::exit(1);
+
+        if aoc_cli::check().is_err() {
+            eprintln!(
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            );
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::download_puzzle(day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    match fs::read_to_string(&path) {
+        Ok(markdown) => page(&render(&markdown)),
+        Err(e) => {
+            eprintln!("Failed to read \"{path}\": {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        ANSI_CODE, ANSI_HIGHLIGHT, ANSI_UNDERLINE, PART_TWO_GUTTER, heading, inline, render,
     };
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn reads_headings() {
+        assert_eq!(
+            heading("\\--- Day 1: Historian Hysteria ---"),
+            Some("--- Day 1: Historian Hysteria ---")
+        );
+        assert_eq!(heading("## Title"), Some("Title"));
+        assert_eq!(heading("----------"), None);
+        assert_eq!(heading("Some --- text"), None);
+    }
+
+    #[test]
+    fn renders_inline_markup() {
+        assert_eq!(
+            inline("a *b* \\*c\\* snake_case"),
+            format!("a {ANSI_BOLD}b{ANSI_RESET} *c* snake_case")
+        );
+        assert_eq!(
+            inline("is `*11*`, not `7`"),
+            format!("is {ANSI_BOLD}{ANSI_CODE}11{ANSI_RESET}, not {ANSI_CODE}7{ANSI_RESET}")
+        );
+        assert_eq!(
+            inline("*`31`* [more](/2024/day/1)"),
+            format!(
+                "{ANSI_BOLD}{ANSI_BOLD}{ANSI_CODE}31{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET} {ANSI_UNDERLINE}more{ANSI_RESET}"
+            )
+        );
+    }
+
+    #[test]
+    fn renders_descriptions() {
+        let rendered = render(
+            "\\--- Day 1: A ---\n----------\n\n```\n1 2\n```\n\n* item\n\n\\--- Part Two ---\n----------\n\nMore.\n",
+        );
+
+        let gutter = format!("{ANSI_HIGHLIGHT}{PART_TWO_GUTTER}{ANSI_RESET} ");
+        assert_eq!(
+            rendered,
+            [
+                format!("{ANSI_BOLD}--- Day 1: A ---{ANSI_RESET}"),
+                String::new(),
+                format!("    {ANSI_CODE}1 2{ANSI_RESET}"),
+                String::new(),
+                "  • item".to_string(),
+                String::new(),
+                format!("{ANSI_HIGHLIGHT}{PART_TWO_GUTTER} --- Part Two ---{ANSI_RESET}"),
+                gutter.trim_end().to_string(),
+                format!("{gutter}More."),
+                String::new(),
+            ]
+            .join("\n")
+        );
+    }
 }
@@ -0,0 +1,56 @@
+/// Download the description of a day again once part two is unlocked, and update the examples and
+/// expected answers of its tests.
+use std::process;
+
+use crate::template::commands::example;
+use crate::template::puzzle::Puzzle;
+use crate::template::{Day, aoc_cli};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Use the stored description instead of downloading it again.
+    pub offline: bool,
+    /// Replace existing examples and expectations.
+    pub overwrite: bool,
+}
+
+pub fn handle(day: Day, options: &Options) {
+    if !options.offline {
+        if aoc_cli::check().is_err() {
+            eprintln!(
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            );
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::download_puzzle(day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
+    }
+
+    let Some(puzzle) = Puzzle::read(day) else {
+        eprintln!("No description of day {day} found, download it with `cargo download {day}`.");
+        process::exit(1);
+    };
+
+    if !puzzle.has_part_two {
+        eprintln!("Part two of day {day} is not unlocked yet, submit part one first.");
+        process::exit(1);
+    }
+
+    for part in 1..=2 {
+        println!("Part {part}:");
+
+        let options = example::Options {
+            part,
+            overwrite: options.overwrite,
+            ..example::Options::default()
+        };
+
+        if let Err(e) = example::extract(day, &puzzle, &options) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
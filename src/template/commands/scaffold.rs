@@ -1,79 +1,440 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Day, aoc_cli};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Templates for common kinds of puzzles, by name.
+const TEMPLATES: [(&str, &str); 5] = [
+    (
+        "default",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/default.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "parse-lines",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/parse-lines.txt"
+        )),
+    ),
+    (
+        "shared-parse",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/shared-parse.txt"
+        )),
+    ),
+];
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+/// Templates in this directory, e.g. `grid.txt`, replace the template of the same name or add a new one.
+static USER_TEMPLATES_DIR: &str = "./data/templates";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Replace an existing module. Inputs and examples are never replaced.
+    pub overwrite: bool,
+    /// Only print what would be created.
+    pub dry_run: bool,
+    /// The name of the module template.
+    pub template: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            overwrite: false,
+            dry_run: false,
+            template: "default".into(),
+        }
     }
-    file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// The names of all templates, built-in and from the user directory.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = TEMPLATES.iter().map(|(name, _)| name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATES_DIR) {
+        names.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(String::from))?
+        }));
+    }
+
+    names.sort();
+    names.dedup();
+    names
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn load_template(name: &str) -> Result<String, String> {
+    let is_valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+    if is_valid_name {
+        if let Ok(template) = fs::read_to_string(format!("{USER_TEMPLATES_DIR}/{name}.txt")) {
+            return Ok(template);
         }
-    };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+        if let Some((_, template)) = TEMPLATES.iter().find(|(n, _)| *n == name) {
+            return Ok((*template).to_string());
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    Err(format!(
+        "unknown template `{name}`, expecting one of {}.",
+        template_names().join(", ")
+    ))
+}
+
+/// The puzzle title from a downloaded description, e.g. `Cafeteria` for `--- Day 5: Cafeteria ---`.
+fn puzzle_title(description: &str) -> Option<String> {
+    let heading = description
+        .lines()
+        .find_map(|line| Some(&line[line.find("--- Day ")? + 8..]))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches('-').trim().to_string())
+}
+
+/// Values of the placeholders a template may use, e.g. `%DAY%`.
+fn placeholders(day: Day, year: Option<u16>, title: Option<String>) -> Vec<(&'static str, String)> {
+    let number = day.into_inner();
+
+    vec![
+        ("DAY_NUMBER", number.to_string()),
+        ("DAY", day.to_string()),
+        ("YEAR", year.map(|y| y.to_string()).unwrap_or_default()),
+        (
+            "PUZZLE_URL",
+            year.map_or("https://adventofcode.com".into(), |y| {
+                format!("https://adventofcode.com/{y}/day/{number}")
+            }),
+        ),
+        ("TITLE", title.unwrap_or_else(|| format!("Day {number}"))),
+    ]
+}
+
+/// Replace `%NAME%` placeholders in `template`. Returns the filled template and unknown placeholders, which are kept.
+fn fill(template: &str, values: &[(&str, String)]) -> (String, Vec<String>) {
+    let mut filled = String::with_capacity(template.len());
+    let mut unknown = vec![];
+    let mut rest = template;
+
+    while let Some(start) = rest.find('%') {
+        filled.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let name = after.find('%').map(|end| &after[..end]).filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase() || c == '_')
+        });
+
+        match name {
+            Some(name) => {
+                match values.iter().find(|(n, _)| *n == name) {
+                    Some((_, value)) => filled.push_str(value),
+                    None => {
+                        filled.push_str(&format!("%{name}%"));
+                        unknown.push(name.to_string());
+                    }
+                }
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                filled.push('%');
+                rest = after;
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    filled.push_str(rest);
+    (filled, unknown)
+}
+
+/// What scaffolding does with a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    /// Replace an existing module, with `--overwrite`.
+    Overwrite,
+    /// Leave an existing file alone.
+    Keep,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PlannedFile {
+    path: String,
+    kind: &'static str,
+    contents: String,
+    action: Action,
+}
+
+/// Decide what to do with each file. Only the module is ever overwritten, inputs and examples are kept if they exist.
+fn plan(
+    day: Day,
+    module: String,
+    overwrite: bool,
+    exists: impl Fn(&str) -> bool,
+) -> Vec<PlannedFile> {
+    [
+        (format!("src/bin/{day}.rs"), "module", module, overwrite),
+        (
+            format!("data/inputs/{day}.txt"),
+            "input",
+            String::new(),
+            false,
+        ),
+        (
+            format!("data/examples/{day}.txt"),
+            "example",
+            String::new(),
+            false,
+        ),
+    ]
+    .into_iter()
+    .map(|(path, kind, contents, overwrite)| {
+        let action = match (exists(&path), overwrite) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Overwrite,
+            (true, false) => Action::Keep,
+        };
+        PlannedFile {
+            path,
+            kind,
+            contents,
+            action,
         }
+    })
+    .collect()
+}
+
+fn print_plan(planned: &[PlannedFile]) {
+    for file in planned {
+        let (verb, note) = match file.action {
+            Action::Create => ("create", ""),
+            Action::Overwrite => ("overwrite", ""),
+            Action::Keep => ("keep", ", exists"),
+        };
+        println!("  {verb:<9}  {} ({}{note})", file.path, file.kind);
     }
+}
+
+/// Write the planned files. If any write fails, files written so far are restored, so that nothing is half scaffolded.
+fn apply(planned: &[PlannedFile]) -> Result<(), String> {
+    // paths and previous contents of everything written, `None` for new files.
+    let mut written: Vec<(&str, Option<Vec<u8>>)> = vec![];
+
+    let result = planned
+        .iter()
+        .filter(|file| file.action != Action::Keep)
+        .try_for_each(|file| {
+            let previous = match file.action {
+                Action::Overwrite => Some(fs::read(&file.path).map_err(|e| (file, e))?),
+                _ => None,
+            };
+
+            let mut options = OpenOptions::new();
+            match file.action {
+                Action::Overwrite => options.create(true).truncate(true),
+                _ => options.create_new(true),
+            };
+
+            options
+                .write(true)
+                .open(&file.path)
+                .and_then(|mut f| f.write_all(file.contents.as_bytes()))
+                .map_err(|e| (file, e))?;
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            written.push((&file.path, previous));
+            Ok(())
+        });
+
+    result.map_err(|(file, e)| {
+        for (path, previous) in written.iter().rev() {
+            let _ = match previous {
+                Some(contents) => fs::write(path, contents),
+                None => fs::remove_file(path),
+            };
         }
+        format!("failed to write {} file \"{}\": {e}", file.kind, file.path)
+    })
+}
+
+pub fn handle(day: Day, options: &Options) {
+    let template = match load_template(&options.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
+    };
+
+    let title = fs::read_to_string(format!("data/puzzles/{day}.md"))
+        .ok()
+        .and_then(|description| puzzle_title(&description));
+    let (contents, unknown) = fill(&template, &placeholders(day, aoc_cli::get_year(), title));
+
+    for name in unknown {
+        eprintln!(
+            "Warning: unknown placeholder `%{name}%` in template `{}`.",
+            options.template
+        );
+    }
+
+    let planned = plan(day, contents, options.overwrite, |path| {
+        Path::new(path).exists()
+    });
+
+    if options.dry_run {
+        println!("Scaffolding day {day} would:");
+        print_plan(&planned);
+        println!("---");
+        println!("Dry run, no files were written.");
+        return;
+    }
+
+    println!("Scaffolding day {day}:");
+    print_plan(&planned);
+
+    if let Err(e) = apply(&planned) {
+        eprintln!("Failed to scaffold, no files were changed: {e}");
+        process::exit(1);
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if planned.iter().all(|file| file.action == Action::Keep) {
+        println!("🎄 Day {day} is already scaffolded, use `--overwrite` to replace its module.");
+    } else {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{
+        Action, PlannedFile, TEMPLATES, apply, fill, load_template, placeholders, plan,
+        puzzle_title,
+    };
+    use crate::day;
+
+    #[test]
+    fn rolls_back_failed_scaffolds() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+        fs::write(path("module.rs"), "old").unwrap();
+
+        let file = |path: String, action| PlannedFile {
+            path,
+            kind: "test",
+            contents: "new".into(),
+            action,
+        };
+        let planned = [
+            file(path("module.rs"), Action::Overwrite),
+            file(path("input.txt"), Action::Create),
+            file(path("missing/example.txt"), Action::Create),
+        ];
+
+        assert!(apply(&planned).is_err());
+        assert_eq!(fs::read_to_string(path("module.rs")).unwrap(), "old");
+        assert!(!dir.join("input.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn plans_only_missing_files() {
+        let exists = |path: &str| path != "data/examples/05.txt";
+
+        let actions = |overwrite| -> Vec<(String, Action)> {
+            plan(day!(5), "mod".into(), overwrite, exists)
+                .into_iter()
+                .map(|file| (file.path, file.action))
+                .collect()
+        };
+
+        assert_eq!(
+            actions(false),
+            [
+                ("src/bin/05.rs".into(), Action::Keep),
+                ("data/inputs/05.txt".into(), Action::Keep),
+                ("data/examples/05.txt".into(), Action::Create),
+            ]
+        );
+        assert_eq!(
+            actions(true),
+            [
+                ("src/bin/05.rs".into(), Action::Overwrite),
+                ("data/inputs/05.txt".into(), Action::Keep),
+                ("data/examples/05.txt".into(), Action::Create),
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        let values = placeholders(day!(5), Some(2025), Some("Cafeteria".into()));
+        let (filled, unknown) = fill(
+            "solution!(%DAY_NUMBER%); // %TITLE% %PUZZLE_URL% data/%DAY%.txt %NAME% 10 % 3 %",
+            &values,
+        );
+
+        assert_eq!(
+            filled,
+            "solution!(5); // Cafeteria https://adventofcode.com/2025/day/5 data/05.txt %NAME% 10 % 3 %"
+        );
+        assert_eq!(unknown, ["NAME"]);
+    }
+
+    #[test]
+    fn falls_back_without_year_and_title() {
+        let values = placeholders(day!(12), None, None);
+        let (filled, _) = fill("%TITLE%: %PUZZLE_URL%%YEAR%", &values);
+
+        assert_eq!(filled, "Day 12: https://adventofcode.com");
+    }
+
+    #[test]
+    fn reads_puzzle_titles() {
+        assert_eq!(
+            puzzle_title("\n## --- Day 5: Cafeteria ---\n\nThe elves...").as_deref(),
+            Some("Cafeteria")
+        );
+        assert_eq!(puzzle_title("no heading"), None);
+    }
+
+    #[test]
+    fn loads_templates() {
+        for (name, _) in TEMPLATES {
+            let template = load_template(name).unwrap();
+            assert!(template.contains("advent_of_code::solution!(%DAY_NUMBER%"));
+        }
+
+        assert!(load_template("missing").is_err());
+        assert!(load_template("../Cargo").is_err());
+    }
 }
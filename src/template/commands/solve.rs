@@ -1,28 +1,86 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::limits::{self, Limits};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, InputSource, log, visualize};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+/// Options of `cargo solve`, forwarded to the solution binary.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub release: bool,
+    /// Build with the `dhat-heap` feature to write a heap profile.
+    pub dhat: bool,
+    /// Build with the `alloc-stats` feature to print allocation statistics per part.
+    pub mem: bool,
+    /// Build with the `profile` feature to write a flamegraph per part.
+    pub profile: bool,
+    pub submit: Option<u8>,
+    pub input: InputSource,
+    pub visualize: visualize::Options,
+    pub verbosity: log::Verbosity,
+}
+
+pub fn handle(day: Day, options: &Options) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+    let mut features = vec![];
 
-    if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
-    } else if release {
+    if options.dhat || options.profile {
+        // the `dhat` profile is an optimized build with debug symbols.
+        cmd_args.extend(["--profile".to_string(), "dhat".to_string()]);
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
+    if options.dhat {
+        features.push("dhat-heap");
+    }
+
+    if options.profile {
+        features.push("profile");
+    }
+
+    if options.mem {
+        features.push("alloc-stats");
+    }
+
+    if options.visualize.is_active() {
+        features.push("visualize");
+    }
+
+    if !features.is_empty() {
+        cmd_args.extend(["--features".to_string(), features.join(",")]);
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    cmd_args.extend(options.input.to_args());
+    cmd_args.extend(options.visualize.to_args());
+    cmd_args.extend(options.verbosity.to_args());
+
+    if options.profile {
+        cmd_args.push("--profile".to_string());
+    }
+
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    let limits = Limits::for_day(day);
+
+    if limits.is_limited() {
+        // build up front, so that build times do not count towards the timeout.
+        let separator = cmd_args.iter().position(|arg| arg == "--").unwrap();
+        let status = Command::new("cargo")
+            .arg("build")
+            .args(&cmd_args[1..separator])
+            .status()
+            .unwrap();
+
+        if !status.success() {
+            process::exit(1);
+        }
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +88,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let (_, exceeded) = limits::supervise(&mut cmd, limits).unwrap();
+
+    if let Some(exceeded) = exceeded {
+        println!("{ANSI_BOLD}Aborted:{ANSI_RESET} {exceeded}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,32 @@
+use std::process;
+
+use crate::template::history::format_timestamp;
+use crate::template::{readme_stars, submissions};
+
+pub fn handle() {
+    let progress = submissions::progress();
+
+    for (day, parts) in &progress {
+        for (index, solved) in parts.iter().enumerate() {
+            match solved {
+                Some(Some(timestamp)) => println!(
+                    "Day {day}, part {}: ⭐ accepted {} UTC",
+                    index + 1,
+                    format_timestamp(*timestamp)
+                ),
+                Some(None) => println!("Day {day}, part {}: ⭐", index + 1),
+                None => {}
+            }
+        }
+    }
+
+    let stars = progress.values().flatten().filter(|s| s.is_some()).count();
+
+    if let Err(e) = readme_stars::update(&progress) {
+        eprintln!("Failed to update the stars table: {e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Updated the stars table with {stars} ⭐ in \"README.md\".");
+}
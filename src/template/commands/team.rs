@@ -0,0 +1,239 @@
+use std::path::{Path, PathBuf};
+use std::{fs, io, process};
+
+use crate::template::run_multi::RunOptions;
+use crate::template::run_multi::child_commands::{self, PartResult};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, InputSource};
+
+/// A teammate's input for a day, stored as `data/inputs/NN/<name>.txt`.
+/// Known answers live next to it in `<name>.answers`, one line per part.
+struct TeamInput {
+    name: String,
+    path: PathBuf,
+    answers: [Option<String>; 2],
+}
+
+enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+    Missing,
+}
+
+struct PartVerdict {
+    part: u8,
+    verdict: Verdict,
+    nanos: Option<f64>,
+}
+
+/// The outcome of running the solution on one input.
+enum Outcome {
+    Solved(Vec<PartVerdict>),
+    /// The solution printed nothing.
+    NotSolved,
+    /// The solution could not be run.
+    Failed(String),
+}
+
+fn get_team_dir(day: Day) -> String {
+    format!("data/inputs/{day}")
+}
+
+fn read_team_inputs(dir: &Path) -> io::Result<Vec<TeamInput>> {
+    let mut inputs = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().map(|x| x.to_string_lossy().into_owned()) else {
+            continue;
+        };
+
+        let answers = match fs::read_to_string(path.with_extension("answers")) {
+            Ok(contents) => parse_answers(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => [None, None],
+            Err(e) => return Err(e),
+        };
+
+        inputs.push(TeamInput {
+            name,
+            path,
+            answers,
+        });
+    }
+
+    inputs.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Parse an answers file: the first line holds part one, the second line part two.
+/// Empty lines or `-` mark an unknown answer.
+fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(|l| match l.trim() {
+        "" | "-" => None,
+        answer => Some(answer.to_string()),
+    });
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+fn judge(expected: Option<&String>, result: Option<&PartResult>) -> Verdict {
+    match (expected, result.and_then(|r| r.answer.as_ref())) {
+        (None, None) => Verdict::Missing,
+        (None, Some(_)) => Verdict::Unknown,
+        (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+        (Some(expected), _) => Verdict::Fail {
+            expected: expected.clone(),
+        },
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    nanos.map_or_else(String::new, |n| {
+        format!(" ({:.1?})", std::time::Duration::from_nanos(n as u64))
+    })
+}
+
+pub fn handle(day: Day, is_release: bool, is_timed: bool) {
+    let dir = get_team_dir(day);
+
+    let inputs = match read_team_inputs(Path::new(&dir)) {
+        Ok(inputs) if !inputs.is_empty() => inputs,
+        Ok(_) => {
+            eprintln!("No inputs found in \"{dir}\". Add them as \"{dir}/<name>.txt\".");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read team inputs from \"{dir}\": {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut summary: Vec<(String, Outcome)> = vec![];
+
+    for (index, input) in inputs.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day} - {}{ANSI_RESET}", input.name);
+        println!("------");
+
+        let output = match child_commands::run_solution(
+            day,
+            RunOptions {
+                is_release,
+                is_timed,
+                ..RunOptions::default()
+            },
+            &InputSource::Path(input.path.clone()),
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run the solution: {e}");
+                summary.push((input.name.clone(), Outcome::Failed(e.to_string())));
+                continue;
+            }
+        };
+
+        if output.is_empty() {
+            println!("Not solved.");
+            summary.push((input.name.clone(), Outcome::NotSolved));
+            continue;
+        }
+
+        let results = child_commands::parse_results(&output);
+
+        let verdicts = (1..=2)
+            .map(|part| {
+                let result = results.iter().find(|r| r.part == part);
+                PartVerdict {
+                    part,
+                    verdict: judge(input.answers[usize::from(part) - 1].as_ref(), result),
+                    nanos: result.and_then(|r| r.nanos),
+                }
+            })
+            .collect();
+
+        summary.push((input.name.clone(), Outcome::Solved(verdicts)));
+    }
+
+    println!("\n{ANSI_BOLD}Results{ANSI_RESET}");
+    println!("------");
+
+    let mut failures = 0;
+    let width = summary
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+
+    for (name, outcome) in &summary {
+        let verdicts = match outcome {
+            Outcome::Solved(verdicts) => verdicts,
+            Outcome::NotSolved => {
+                println!("{name:<width$}  {ANSI_ITALIC}not solved{ANSI_RESET}");
+                continue;
+            }
+            Outcome::Failed(e) => {
+                failures += 1;
+                println!("{name:<width$}  ✖ failed to run: {e}");
+                continue;
+            }
+        };
+
+        let parts: Vec<String> = verdicts
+            .iter()
+            .map(
+                |PartVerdict {
+                     part,
+                     verdict,
+                     nanos,
+                 }| {
+                    let timing = format_nanos(*nanos);
+                    match verdict {
+                        Verdict::Pass => format!("Part {part}: ✔{timing}"),
+                        Verdict::Fail { expected } => {
+                            failures += 1;
+                            format!("Part {part}: ✖ expected {expected}{timing}")
+                        }
+                        Verdict::Unknown => {
+                            format!("Part {part}: {ANSI_ITALIC}unknown{ANSI_RESET}{timing}")
+                        }
+                        Verdict::Missing => format!("Part {part}: -"),
+                    }
+                },
+            )
+            .collect();
+
+        println!("{name:<width$}  {}", parts.join("  "));
+    }
+
+    if failures > 0 {
+        println!("\n{ANSI_BOLD}{failures} answer(s) or run(s) failed.{ANSI_RESET}");
+        process::exit(1);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_answers;
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("42\n1337\n"),
+            [Some("42".into()), Some("1337".into())]
+        );
+        assert_eq!(parse_answers("-\n1337"), [None, Some("1337".into())]);
+        assert_eq!(parse_answers("42"), [Some("42".into()), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+}
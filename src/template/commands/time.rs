@@ -1,40 +1,222 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::{fs, process};
 
-use crate::template::run_multi::run_multi;
+use crate::template::history::{self, Entry};
+use crate::template::run_multi::{RunOptions, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, DayFilter, all_days, aoc_cli, readme_benchmarks,
+    report, submissions,
+};
+
+pub fn handle(
+    filter: &DayFilter,
+    run_all: bool,
+    store: bool,
+    measure_memory: bool,
+    perf_counters: bool,
+) {
+    if store && filter.part.is_some() {
+        eprintln!("Timings of a single part cannot be stored, remove `--part` to store them.");
+        process::exit(1);
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
+    let mut days_to_run = filter.select(&submissions::progress());
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+    // when neither days, a status nor the `--all` flag are set, filter out days that are fully benched.
+    if !run_all && filter.days.is_none() && filter.status.is_none() {
+        days_to_run.retain(|day| !stored_timings.is_day_complete(*day));
+    }
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        // the counting allocator would slow down every benchmark iteration.
+        measure_memory: false,
+        perf_counters,
+        // days are benched one at a time, so they do not compete for the CPU.
+        jobs: 1,
+        part: filter.part,
+    };
+    let mut timings = run_multi(&days_to_run, options).unwrap();
+
+    if measure_memory {
+        println!("\n{ANSI_ITALIC}Measuring allocations in a separate run...{ANSI_RESET}\n");
+
+        let options = RunOptions {
+            is_timed: false,
+            measure_memory: true,
+            perf_counters: false,
+            ..options
+        };
+        let memory = run_multi(&days_to_run, options).unwrap();
+        timings = timings.with_memory_of(&memory);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        if let Err(e) = history::append(&timings) {
+            eprintln!("Failed to append benchmarks to history: {e}");
+        }
+
         println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
 }
+
+/// The time of `part` in a history entry, or why it is missing.
+fn part_cell(entry: &Entry, part: u8) -> (String, Option<f64>) {
+    let time = match part {
+        1 => entry.timing.part_1,
+        _ => entry.timing.part_2,
+    };
+
+    match time {
+        Some(time) => (time.to_string(), Some(time.nanos)),
+        None => (
+            entry.timing.aborted.clone().unwrap_or_else(|| "-".into()),
+            None,
+        ),
+    }
+}
+
+/// Relative change to the previous time, e.g. `▼ 12.5%` for a speedup.
+fn format_change(previous: f64, current: f64) -> String {
+    let change = (current - previous) / previous * 100.0;
+    match change {
+        c if c.abs() < 0.05 => "= 0.0%".into(),
+        c if c < 0.0 => format!("▼ {:.1}%", -c),
+        c => format!("▲ {c:.1}%"),
+    }
+}
+
+fn print_part_history(entries: &[&Entry], part: u8) {
+    println!("{ANSI_ITALIC}Part {part}{ANSI_RESET}");
+
+    let commit_width = entries
+        .iter()
+        .map(|e| e.commit.as_deref().unwrap_or("-").len())
+        .max()
+        .unwrap_or(0);
+    let machine_width = entries.iter().map(|e| e.machine.len()).max().unwrap_or(0);
+
+    // times are only comparable on the same machine.
+    let mut previous: HashMap<&str, f64> = HashMap::new();
+
+    for entry in entries {
+        let (time, nanos) = part_cell(entry, part);
+
+        let change = match (nanos, previous.get(entry.machine.as_str())) {
+            (Some(current), Some(previous)) => format_change(*previous, current),
+            _ => String::new(),
+        };
+
+        if let Some(nanos) = nanos {
+            previous.insert(&entry.machine, nanos);
+        }
+
+        let line = format!(
+            "{}  {:<commit_width$}  {:<machine_width$}  {time:>10}  {change}",
+            history::format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry.machine,
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+/// Print how the benchmarks of each day in `filter` evolved, per part.
+pub fn history(filter: &DayFilter) {
+    let entries = history::read();
+    let selected = filter.select(&submissions::progress());
+
+    let days: Vec<Day> = all_days()
+        .filter(|day| selected.contains(day))
+        .filter(|day| entries.iter().any(|e| e.timing.day == *day))
+        .collect();
+
+    if days.is_empty() {
+        println!("No benchmark history found, store benchmarks with `cargo time --store`.");
+        return;
+    }
+
+    for (index, day) in days.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let day_entries: Vec<&Entry> = entries.iter().filter(|e| e.timing.day == *day).collect();
+
+        for part in 1..=2 {
+            if filter.part.is_none_or(|p| p == part) {
+                print_part_history(&day_entries, part);
+            }
+        }
+    }
+}
+
+static REPORT_FILE_PATH: &str = "./data/report.html";
+
+/// Write an HTML report of the stored benchmarks of each day in `filter`, with their history.
+pub fn report(filter: &DayFilter, log_scale: bool) {
+    let timings = Timings::read_from_file();
+    let selected = filter.select(&submissions::progress());
+
+    let timings = Timings {
+        data: timings
+            .data
+            .into_iter()
+            .filter(|t| selected.contains(&t.day))
+            .collect(),
+    };
+
+    if timings.data.is_empty() {
+        println!("No benchmarks found, store benchmarks with `cargo time --store`.");
+        return;
+    }
+
+    let history: Vec<Entry> = history::read()
+        .into_iter()
+        .filter(|e| selected.contains(&e.timing.day))
+        .collect();
+
+    let title = match aoc_cli::get_year() {
+        Some(year) => format!("Advent of Code {year} benchmarks"),
+        None => "Advent of Code benchmarks".into(),
+    };
+
+    let html = report::render(&timings, &history, &title, log_scale);
+    if let Err(e) = fs::write(REPORT_FILE_PATH, html) {
+        eprintln!("Failed to write report: {e}");
+        process::exit(1);
+    }
+
+    println!("🎄 Wrote benchmark report to \"{REPORT_FILE_PATH}\".");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_change;
+
+    #[test]
+    fn formats_changes() {
+        assert_eq!(format_change(100.0, 87.5), "▼ 12.5%");
+        assert_eq!(format_change(100.0, 150.0), "▲ 50.0%");
+        assert_eq!(format_change(100.0, 100.0), "= 0.0%");
+    }
+}
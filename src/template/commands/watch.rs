@@ -0,0 +1,310 @@
+/// Re-run a day's examples and puzzle input whenever its files change.
+///
+/// Files are polled for modification times, so this works everywhere without a file watcher.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{fs, io, thread};
+
+use crate::template::run_multi::child_commands;
+use crate::template::run_multi::{RunOptions, get_path_for_bin};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, InputSource};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors often save in several steps, wait for them to finish before running.
+const SETTLE_DELAY: Duration = Duration::from_millis(100);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Modification times of all files a day depends on. Missing files map to `None`.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The source, puzzle input and all examples of a day, including examples created while watching.
+fn watched_files(day: Day) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(get_path_for_bin(day)),
+        PathBuf::from(format!("data/inputs/{day}.txt")),
+        PathBuf::from(format!("data/examples/{day}.txt")),
+    ];
+
+    if let Ok(entries) = fs::read_dir("data/examples") {
+        let prefix = format!("{day}-");
+        files.extend(
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+                }),
+        );
+    }
+
+    files
+}
+
+fn snapshot(day: Day) -> Snapshot {
+    watched_files(day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// The outcome of a single test, parsed from the output of the test harness.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct TestOutcome {
+    name: String,
+    passed: bool,
+    /// The panic message of a failed test, unless it failed an `assert_eq!`.
+    message: Option<String>,
+    /// `right` and `left` of a failed `assert_eq!(result, expected)`.
+    expected: Option<String>,
+    actual: Option<String>,
+}
+
+/// Parse the outcomes of all tests that ran from the (uncolored) stdout of `cargo test`.
+fn parse_test_output(output: &[String]) -> Vec<TestOutcome> {
+    let mut outcomes: Vec<TestOutcome> = output
+        .iter()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let passed = match result.trim() {
+                "ok" => true,
+                "FAILED" => false,
+                _ => return None,
+            };
+            Some(TestOutcome {
+                name: name.to_string(),
+                passed,
+                ..TestOutcome::default()
+            })
+        })
+        .collect();
+
+    let mut current: Option<usize> = None;
+    let mut lines = output.iter();
+
+    while let Some(line) = lines.next() {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            current = outcomes.iter().position(|o| o.name == name);
+            continue;
+        }
+
+        let Some(outcome) = current.map(|index| &mut outcomes[index]) else {
+            continue;
+        };
+
+        if line.starts_with("thread '") && line.contains("panicked at") {
+            outcome.message = lines.next().map(|l| l.trim().to_string());
+        } else if let Some(actual) = line.trim_start().strip_prefix("left: ") {
+            outcome.actual = Some(actual.to_string());
+        } else if let Some(expected) = line.trim_start().strip_prefix("right: ") {
+            outcome.expected = Some(expected.to_string());
+        }
+    }
+
+    outcomes
+}
+
+/// Run the tests of a day, which check the examples. Returns `true` if all of them passed.
+fn run_examples(day: Day, is_release: bool) -> io::Result<bool> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--bin", &day.to_string()]);
+    if is_release {
+        cmd.arg("--release");
+    }
+
+    let output = cmd
+        .args(["--", "--color", "never"])
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::null())
+        .output()?;
+
+    let stdout: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect();
+    let outcomes = parse_test_output(&stdout);
+
+    if outcomes.is_empty() {
+        if output.status.success() {
+            println!("{ANSI_ITALIC}No tests found.{ANSI_RESET}");
+            return Ok(true);
+        }
+
+        // the bin did not compile, show the errors without cargo's progress lines.
+        String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter(|line| {
+                let line = line.trim_start();
+                !["Compiling ", "Finished ", "Running "]
+                    .iter()
+                    .any(|prefix| line.starts_with(prefix))
+            })
+            .for_each(|line| println!("{line}"));
+        return Ok(false);
+    }
+
+    for outcome in &outcomes {
+        let name = outcome
+            .name
+            .strip_prefix("tests::")
+            .unwrap_or(&outcome.name);
+
+        if outcome.passed {
+            println!("✔ {name}");
+            continue;
+        }
+
+        println!("✖ {name}");
+        match (&outcome.expected, &outcome.actual) {
+            (Some(expected), Some(actual)) => {
+                println!("    expected: {expected}");
+                println!("    actual:   {actual}");
+            }
+            _ => {
+                if let Some(message) = &outcome.message {
+                    println!("    {message}");
+                }
+            }
+        }
+    }
+
+    Ok(outcomes.iter().all(|o| o.passed))
+}
+
+fn run(day: Day, is_release: bool) {
+    print!("{CLEAR_SCREEN}");
+    println!(
+        "{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}watching for changes, press Ctrl-C to stop{ANSI_RESET}"
+    );
+    println!();
+    println!("{ANSI_BOLD}Examples{ANSI_RESET}");
+    println!("------");
+
+    let passed = match run_examples(day, is_release) {
+        Ok(passed) => passed,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            false
+        }
+    };
+
+    println!();
+    println!("{ANSI_BOLD}Puzzle input{ANSI_RESET}");
+    println!("------");
+
+    let input = InputSource::Default;
+    if !passed {
+        println!("{ANSI_ITALIC}Skipped until the examples pass.{ANSI_RESET}");
+    } else if input.path(day).is_none_or(|path| !path.exists()) {
+        println!(
+            "{ANSI_ITALIC}No puzzle input, download it with `cargo download {day}`.{ANSI_RESET}"
+        );
+    } else if let Err(e) = child_commands::run_solution(
+        day,
+        RunOptions {
+            is_release,
+            ..RunOptions::default()
+        },
+        &input,
+    ) {
+        eprintln!("Failed to run solution: {e:?}");
+    }
+}
+
+pub fn handle(day: Day, is_release: bool) {
+    if !Path::new(&get_path_for_bin(day)).exists() {
+        eprintln!("Day {day} has not been scaffolded yet, run `cargo scaffold {day}` first.");
+        process::exit(1);
+    }
+
+    let mut last: Option<Snapshot> = None;
+
+    loop {
+        let current = snapshot(day);
+
+        if last.as_ref() != Some(&current) {
+            if last.is_some() {
+                thread::sleep(SETTLE_DELAY);
+            }
+            last = Some(snapshot(day));
+            run(day, is_release);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{TestOutcome, parse_test_output};
+
+    #[test]
+    fn parses_test_output() {
+        let output: Vec<String> = "
+running 3 tests
+test tests::test_part_one ... FAILED
+test tests::test_part_two ... ok
+test tests::test_parse ... FAILED
+
+failures:
+
+---- tests::test_part_one stdout ----
+
+thread 'tests::test_part_one' (19719) panicked at src/bin/11.rs:138:9:
+assertion `left == right` failed
+  left: Some(5)
+ right: Some(6)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- tests::test_parse stdout ----
+
+thread 'tests::test_parse' (19720) panicked at src/bin/11.rs:150:9:
+not yet implemented
+
+failures:
+    tests::test_part_one
+    tests::test_parse
+
+test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+"
+        .lines()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(
+            parse_test_output(&output),
+            [
+                TestOutcome {
+                    name: "tests::test_part_one".into(),
+                    passed: false,
+                    message: Some("assertion `left == right` failed".into()),
+                    expected: Some("Some(6)".into()),
+                    actual: Some("Some(5)".into()),
+                },
+                TestOutcome {
+                    name: "tests::test_part_two".into(),
+                    passed: true,
+                    ..TestOutcome::default()
+                },
+                TestOutcome {
+                    name: "tests::test_parse".into(),
+                    passed: false,
+                    message: Some("not yet implemented".into()),
+                    ..TestOutcome::default()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,514 @@
+/// Randomized testing of solution parsers and solutions.
+///
+/// A day implements [`InputGenerator`] for its input format and calls [`assert_parser_total`] from a regular `#[test]`:
+/// well-formed inputs must parse, randomly mutated inputs may be rejected but must never panic.
+///
+/// Days with tricky fast paths can additionally compare a solution with a slow, obviously correct reference
+/// implementation via [`assert_matches_reference`]. Mismatches are shrunk to a minimal differing input.
+///
+/// The number of iterations and the seed can be overridden with the `AOC_FUZZ_ITERATIONS` and `AOC_FUZZ_SEED`
+/// environment variables. Failures print the seed needed to reproduce them.
+use std::env;
+use std::fmt::Debug;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_ITERATIONS: usize = 500;
+const DEFAULT_SEED: u64 = 0x2025_1201;
+
+/// A small, seedable pseudo random number generator (xorshift64*).
+/// Not suitable for anything but generating test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the all-zero state is a fixed point of xorshift.
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A value in `range`. Panics if the range is empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "cannot pick from an empty range");
+        #[allow(clippy::cast_possible_truncation)]
+        let offset = (self.next_u64() % (range.len() as u64)) as usize;
+        range.start + offset
+    }
+
+    /// `true` with a probability of `1 / n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.range(0..n) == 0
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.one_in(2)
+    }
+
+    /// A random element of `items`. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    /// A string of `len` characters drawn from `alphabet`.
+    pub fn string_from(&mut self, alphabet: &[char], len: usize) -> String {
+        (0..len).map(|_| *self.choose(alphabet)).collect()
+    }
+}
+
+/// Generates well-formed puzzle inputs (or parts of inputs) of a day.
+pub trait InputGenerator {
+    fn generate(&self, rng: &mut Rng) -> String;
+}
+
+impl<F: Fn(&mut Rng) -> String> InputGenerator for F {
+    fn generate(&self, rng: &mut Rng) -> String {
+        self(rng)
+    }
+}
+
+/// Characters that are mixed into mutated inputs in addition to the characters of the input itself.
+const NOISE: &[char] = &[
+    '\n', ' ', '\t', '-', '+', ',', ':', '0', '9', 'a', 'Z', '#', '.', '(', ']', '}', 'é', '😱',
+];
+
+/// Apply a handful of random edits to `input`: deletions, insertions, replacements and line shuffles.
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut alphabet: Vec<char> = input.chars().collect();
+    alphabet.extend_from_slice(NOISE);
+
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.range(1..4) {
+        match rng.range(0..6) {
+            0 if !chars.is_empty() => {
+                let start = rng.range(0..chars.len());
+                let end = rng.range(start..chars.len()) + 1;
+                chars.drain(start..end);
+            }
+            1 => {
+                let at = rng.range(0..chars.len() + 1);
+                chars.insert(at, *rng.choose(&alphabet));
+            }
+            2 if !chars.is_empty() => {
+                let at = rng.range(0..chars.len());
+                chars[at] = *rng.choose(&alphabet);
+            }
+            3 if !chars.is_empty() => {
+                chars.truncate(rng.range(0..chars.len()));
+            }
+            4 => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.lines().collect();
+                if !lines.is_empty() {
+                    let line = *rng.choose(&lines);
+                    let at = rng.range(0..lines.len() + 1);
+                    lines.insert(at, line);
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+            _ => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.lines().collect();
+                if lines.len() > 1 {
+                    let a = rng.range(0..lines.len());
+                    let b = rng.range(0..lines.len());
+                    lines.swap(a, b);
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Iteration count and seed of a randomized test run.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub iterations: usize,
+    pub seed: u64,
+}
+
+impl Config {
+    /// Read the configuration from `AOC_FUZZ_ITERATIONS` and `AOC_FUZZ_SEED`.
+    /// `AOC_FUZZ_SEED=random` picks a fresh seed for every run.
+    pub fn from_env() -> Self {
+        let iterations = env::var("AOC_FUZZ_ITERATIONS")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(DEFAULT_ITERATIONS);
+
+        let seed = match env::var("AOC_FUZZ_SEED").as_deref() {
+            Ok("random") => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(DEFAULT_SEED, |x| x.as_secs() ^ u64::from(x.subsec_nanos())),
+            Ok(x) => x.parse().unwrap_or(DEFAULT_SEED),
+            Err(_) => DEFAULT_SEED,
+        };
+
+        Self { iterations, seed }
+    }
+}
+
+/// Run `f`, turning a panic into an error carrying the panic message.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|x| (*x).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "<non-string panic payload>".into())
+    })
+}
+
+/// Assert that `parse` accepts every input produced by `generator`
+/// and returns (rather than panics) for mutated and degenerate inputs.
+pub fn assert_parser_total<T, E: Debug>(
+    generator: &impl InputGenerator,
+    parse: impl Fn(&str) -> Result<T, E>,
+) {
+    assert_parser_total_with(Config::from_env(), generator, parse);
+}
+
+/// Like [`assert_parser_total`], with an explicit [`Config`].
+pub fn assert_parser_total_with<T, E: Debug>(
+    config: Config,
+    generator: &impl InputGenerator,
+    parse: impl Fn(&str) -> Result<T, E>,
+) {
+    let check = |input: &str, iteration: usize| {
+        catch_panic(|| parse(input)).unwrap_or_else(|msg| {
+            panic!(
+                "parser panicked on input {input:?}: {msg}\n\
+                (iteration {iteration}, reproduce with AOC_FUZZ_SEED={})",
+                config.seed
+            )
+        })
+    };
+
+    for input in ["", "\n", " ", "\n\n"] {
+        let _ = check(input, 0);
+    }
+
+    let mut rng = Rng::new(config.seed);
+
+    for iteration in 0..config.iterations {
+        let input = generator.generate(&mut rng);
+
+        if let Err(e) = check(&input, iteration) {
+            panic!(
+                "parser rejected generated input {input:?}: {e:?}\n\
+                (iteration {iteration}, reproduce with AOC_FUZZ_SEED={})",
+                config.seed
+            );
+        }
+
+        let mutated = mutate(&input, &mut rng);
+        let _ = check(&mutated, iteration);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of running a solution: its result or the message it panicked with.
+type Outcome<R> = Result<R, String>;
+
+/// Whether the solution differs from the reference: it returns another result or panics where the reference does not.
+/// Inputs on which the reference panics are not valid puzzle inputs, so they never differ.
+fn differs<R: PartialEq>(expected: &Outcome<R>, actual: &Outcome<R>) -> bool {
+    match (expected, actual) {
+        (Ok(expected), Ok(actual)) => expected != actual,
+        (Ok(_), Err(_)) => true,
+        (Err(_), _) => false,
+    }
+}
+
+/// Assert that `solution` returns the same result as `reference` for every input produced by `generator`.
+/// A mismatch is shrunk to a minimal input that still differs before failing.
+pub fn assert_matches_reference<R: PartialEq + Debug>(
+    generator: &impl InputGenerator,
+    reference: impl Fn(&str) -> R,
+    solution: impl Fn(&str) -> R,
+) {
+    assert_matches_reference_with(Config::from_env(), generator, reference, solution);
+}
+
+/// Like [`assert_matches_reference`], with an explicit [`Config`].
+pub fn assert_matches_reference_with<R: PartialEq + Debug>(
+    config: Config,
+    generator: &impl InputGenerator,
+    reference: impl Fn(&str) -> R,
+    solution: impl Fn(&str) -> R,
+) {
+    let run = |input: &str| {
+        (
+            catch_panic(|| reference(input)),
+            catch_panic(|| solution(input)),
+        )
+    };
+
+    let mut rng = Rng::new(config.seed);
+
+    for iteration in 0..config.iterations {
+        let input = generator.generate(&mut rng);
+
+        let (expected, actual) = run(&input);
+
+        if let Err(msg) = &expected {
+            panic!(
+                "reference panicked on generated input {input:?}: {msg}\n\
+                fix the generator or the reference (iteration {iteration}, reproduce with AOC_FUZZ_SEED={})",
+                config.seed
+            );
+        }
+
+        if !differs(&expected, &actual) {
+            continue;
+        }
+
+        let minimal = shrink(&input, |candidate| {
+            let (expected, actual) = run(candidate);
+            differs(&expected, &actual)
+        });
+        let (expected, actual) = run(&minimal);
+
+        panic!(
+            "solution differs from reference on input {minimal:?}\n\
+            reference: {expected:?}\n\
+            solution:  {actual:?}\n\
+            (shrunk from {input:?}, iteration {iteration}, reproduce with AOC_FUZZ_SEED={})",
+            config.seed
+        );
+    }
+}
+
+/// Greedily simplify `input` for as long as `is_failing` holds.
+/// Tries removing lines and list items, and replacing numbers with smaller ones.
+pub fn shrink(input: &str, is_failing: impl Fn(&str) -> bool) -> String {
+    let mut current = input.to_string();
+
+    'outer: loop {
+        for candidate in shrink_candidates(&current) {
+            if is_failing(&candidate) {
+                current = candidate;
+                continue 'outer;
+            }
+        }
+
+        return current;
+    }
+}
+
+/// Simpler variants of `input`, roughly ordered from most to least aggressive.
+fn shrink_candidates(input: &str) -> Vec<String> {
+    let mut candidates = vec![];
+
+    let lines: Vec<&str> = input.lines().collect();
+
+    if lines.len() > 1 {
+        for index in 0..lines.len() {
+            let mut rest = lines.clone();
+            rest.remove(index);
+            candidates.push(rest.join("\n"));
+        }
+    }
+
+    for (index, line) in lines.iter().enumerate() {
+        let items: Vec<&str> = line.split(',').collect();
+        if items.len() < 2 {
+            continue;
+        }
+
+        for item in 0..items.len() {
+            let mut rest = items.clone();
+            rest.remove(item);
+
+            let mut shrunk = lines.clone();
+            let joined = rest.join(",");
+            shrunk[index] = &joined;
+            candidates.push(shrunk.join("\n"));
+        }
+    }
+
+    // replace every number with a few smaller values.
+    let mut start = None;
+    for (index, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(from)) => {
+                start = None;
+                let Ok(value) = input[from..index].parse::<u64>() else {
+                    continue;
+                };
+
+                let digits = &input[from..index];
+
+                let mut smaller = vec![0, 1, value / 2, value - value.min(1)];
+                // dropping a single digit keeps the other digits, e.g. the value modulo a power of ten.
+                smaller.extend((0..digits.len()).filter_map(|skip| {
+                    let mut shorter = digits.to_string();
+                    shorter.remove(skip);
+                    shorter.parse::<u64>().ok()
+                }));
+                smaller.retain(|x| *x < value);
+                smaller.sort_unstable();
+                smaller.dedup();
+
+                for x in smaller {
+                    candidates.push(format!("{}{x}{}", &input[..from], &input[index..]));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    candidates
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Config, Rng, assert_matches_reference_with, assert_parser_total_with, mutate, shrink,
+    };
+
+    fn config() -> Config {
+        Config {
+            iterations: 200,
+            seed: 42,
+        }
+    }
+
+    fn numbers(rng: &mut Rng) -> String {
+        (0..rng.range(1..5))
+            .map(|_| rng.range(0..1000).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn parse_numbers(s: &str) -> Result<Vec<u32>, std::num::ParseIntError> {
+        s.split(',').map(str::parse).collect()
+    }
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_respects_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let x = rng.range(3..6);
+            assert!((3..6).contains(&x));
+        }
+    }
+
+    #[test]
+    fn mutations_are_valid_strings() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let _ = mutate("ab😱\ncd", &mut rng);
+        }
+    }
+
+    #[test]
+    fn accepts_total_parsers() {
+        assert_parser_total_with(config(), &numbers, parse_numbers);
+    }
+
+    #[test]
+    #[should_panic(expected = "parser panicked")]
+    fn detects_panicking_parsers() {
+        assert_parser_total_with(config(), &numbers, |s| parse_numbers(s).map(|nums| nums[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "parser rejected generated input")]
+    fn detects_rejected_inputs() {
+        assert_parser_total_with(config(), &numbers, |s| s.parse::<u32>());
+    }
+
+    #[test]
+    fn accepts_matching_solutions() {
+        assert_matches_reference_with(
+            config(),
+            &numbers,
+            |s| parse_numbers(s).map(|x| x.iter().sum::<u32>()).ok(),
+            |s| parse_numbers(s).map(|x| x.iter().rev().sum::<u32>()).ok(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "solution differs from reference on input \"100\"")]
+    fn reports_minimal_differing_input() {
+        assert_matches_reference_with(
+            config(),
+            &numbers,
+            |s| parse_numbers(s).map(|x| x.iter().sum::<u32>()).ok(),
+            // wrong for numbers of three digits.
+            |s| {
+                parse_numbers(s)
+                    .map(|x| x.iter().map(|n| n % 100).sum::<u32>())
+                    .ok()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "solution differs from reference on input \"0\\n100\"")]
+    fn shrinks_to_inputs_the_reference_accepts() {
+        let lines = |rng: &mut Rng| format!("{}\n{}\n{}", numbers(rng), numbers(rng), numbers(rng));
+        let sum = |s: &str, digits: fn(u32) -> u32| -> u32 {
+            s.lines()
+                .flat_map(|l| parse_numbers(l).unwrap())
+                .map(digits)
+                .sum()
+        };
+
+        assert_matches_reference_with(
+            config(),
+            &lines,
+            // like most reference implementations, panics on malformed inputs: here, less than two lines.
+            |s| {
+                assert!(s.lines().count() >= 2, "expected two lines");
+                sum(s, |n| n)
+            },
+            // wrong for numbers of three digits, and for malformed inputs.
+            |s| match s.lines().count() {
+                0 | 1 => 0,
+                _ => sum(s, |n| n % 100),
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "reference panicked on generated input")]
+    fn detects_panicking_references() {
+        assert_matches_reference_with(
+            config(),
+            &numbers,
+            |s| parse_numbers(s).unwrap()[1],
+            |s| parse_numbers(s).map_or(0, |x| x.get(1).copied().unwrap_or(0)),
+        );
+    }
+
+    #[test]
+    fn shrinks_lines_items_and_numbers() {
+        let minimal = shrink("1,2,30\n4,57", |s| s.contains("20") || s.contains("15"));
+        assert_eq!(minimal, "15");
+    }
+}
@@ -0,0 +1,261 @@
+/// Append-only log of stored benchmarks, so that the performance of a day can be followed over time.
+///
+/// Every `cargo time --store` appends one line per benched day to `data/timings-history.jsonl`,
+/// recording when, at which git commit and on which machine the day was benched next to its timing.
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::{Timing, Timings};
+
+static HISTORY_FILE_PATH: &str = "./data/timings-history.jsonl";
+
+/// Overrides the machine id, e.g. to tell apart benchmarks of a laptop on battery and on power.
+const MACHINE_ENV_VAR: &str = "AOC_MACHINE";
+
+/// A timing of a day, as stored at some point in time.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The abbreviated hash of `HEAD`, suffixed with `-dirty` if there were uncommitted changes
+    /// outside the readme and `data/`.
+    pub commit: Option<String>,
+    pub machine: String,
+    pub timing: Timing,
+}
+
+/// The commit the working tree is based on, if this is a git repository.
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let hash = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    // `cargo time --store` itself rewrites the readme and the stored timings.
+    let dirty = Command::new("git")
+        .args([
+            "status",
+            "--porcelain",
+            "--untracked-files=no",
+            "--",
+            ":(exclude)README.md",
+            ":(exclude)data",
+        ])
+        .output()
+        .is_ok_and(|o| !o.stdout.is_empty());
+
+    Some(if dirty { format!("{hash}-dirty") } else { hash })
+}
+
+/// A name for this machine: `AOC_MACHINE` if set, else its hostname.
+pub fn machine_id() -> String {
+    env::var(MACHINE_ENV_VAR)
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).into_owned())
+        })
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Append `timings` to the history, stamped with the current time, commit and machine.
+pub fn append(timings: &Timings) -> io::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = current_commit();
+    let machine = machine_id();
+
+    let mut lines = String::new();
+    for timing in &timings.data {
+        let entry = Entry {
+            timestamp,
+            commit: commit.clone(),
+            machine: machine.clone(),
+            timing: timing.clone(),
+        };
+        let json = JsonValue::from(&entry)
+            .stringify()
+            .map_err(io::Error::other)?;
+        lines.push_str(&json);
+        lines.push('\n');
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+    file.write_all(lines.as_bytes())
+}
+
+/// Read all entries of the history, oldest first. Without a history file, this is empty.
+pub fn read() -> Vec<Entry> {
+    let Ok(contents) = fs::read_to_string(HISTORY_FILE_PATH) else {
+        return vec![];
+    };
+
+    parse(&contents)
+}
+
+fn parse(contents: &str) -> Vec<Entry> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            let entry = JsonValue::from_str(line)
+                .map_err(|_| "not valid JSON.".to_string())
+                .and_then(|json| Entry::try_from(&json));
+
+            entry
+                .inspect_err(|e| {
+                    eprintln!(
+                        "Skipping line {} of \"{HISTORY_FILE_PATH}\": {e}",
+                        index + 1
+                    );
+                })
+                .ok()
+        })
+        .collect()
+}
+
+/// Format a unix timestamp as a UTC date and time, e.g. `2025-12-01 05:00`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86_400, timestamp % 86_400);
+
+    // convert days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Entry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Entry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Entry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected entry to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("expected entry.timestamp to be a number.")?;
+
+        let machine = json
+            .get("machine")
+            .and_then(|v| v.get::<String>())
+            .ok_or("expected entry.machine to be a string.")?;
+
+        Ok(Entry {
+            timestamp,
+            commit: json.get("commit").and_then(|v| v.get::<String>()).cloned(),
+            machine: machine.clone(),
+            timing: Timing::try_from(json.get("timing").ok_or("expected entry.timing.")?)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Entry, format_timestamp, parse};
+    use crate::day;
+    use crate::template::timings::{PartTiming, Timing};
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_764_565_200), "2025-12-01 05:00");
+        assert_eq!(format_timestamp(951_825_600), "2000-02-29 12:00");
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = Entry {
+            timestamp: 1_764_565_200,
+            commit: Some("abc1234-dirty".into()),
+            machine: "laptop".into(),
+            timing: Timing {
+                day: day!(3),
+                part_1: Some(PartTiming {
+                    nanos: 1_200_000.0,
+                    samples: Some(250),
+                    median_nanos: None,
+                }),
+                part_2: None,
+                total_nanos: 1_200_000.0,
+                parse: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                aborted: None,
+            },
+        };
+
+        let line = JsonValue::from(&entry).stringify().unwrap();
+        let entries = parse(&format!("{line}\nnot json\n\n{line}\n"));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].timestamp, entry.timestamp);
+        assert_eq!(entries[0].commit, entry.commit);
+        assert_eq!(entries[0].machine, "laptop");
+        assert_eq!(entries[0].timing.day, day!(3));
+        assert_eq!(entries[0].timing.part_1, entry.timing.part_1);
+    }
+}
@@ -0,0 +1,215 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use crate::template::Day;
+
+/// Where a solution reads its puzzle input from.
+///
+/// Selected on the command-line of `cargo solve` (and the solution binaries) with:
+///  1. `--input <path>` to read an explicit file, or `--input -` to read stdin.
+///  2. `--example` to read `data/examples/NN.txt`, `--example <N>` to read `data/examples/NN-N.txt`.
+///
+/// Without any of these flags, `data/inputs/NN.txt` is used.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Parse an input source from command-line arguments. Unrelated arguments are ignored.
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Result<Self, InputSourceError> {
+        let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+
+        let input = args.iter().position(|x| *x == "--input");
+        let example = args.iter().position(|x| *x == "--example");
+
+        match (input, example) {
+            (Some(_), Some(_)) => Err(InputSourceError::Conflicting),
+            (Some(index), None) => match args.get(index + 1) {
+                Some(&"-") => Ok(Self::Stdin),
+                Some(path) if !path.starts_with('-') => Ok(Self::Path(PathBuf::from(path))),
+                _ => Err(InputSourceError::MissingPath),
+            },
+            (None, Some(index)) => match args.get(index + 1) {
+                Some(value) if !value.starts_with('-') => value
+                    .parse()
+                    .map(|part| Self::Example(Some(part)))
+                    .map_err(|_| InputSourceError::InvalidExample((*value).to_string())),
+                _ => Ok(Self::Example(None)),
+            },
+            (None, None) => Ok(Self::Default),
+        }
+    }
+
+    /// Arguments that select this input source when passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Default => vec![],
+            Self::Path(path) => vec!["--input".into(), path.to_string_lossy().into_owned()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(part)) => vec!["--example".into(), part.to_string()],
+        }
+    }
+
+    /// Whether this is the puzzle input of the logged-in user, i.e. whether results may be submitted.
+    pub fn is_default(&self) -> bool {
+        *self == Self::Default
+    }
+
+    /// Read the input for `day` to a string.
+    pub fn read(&self, day: Day) -> io::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+            source => fs::read_to_string(source.path(day).unwrap_or_default()),
+        }
+    }
+
+    /// The file backing this input source, `None` for stdin.
+    pub fn path(&self, day: Day) -> Option<PathBuf> {
+        match self {
+            Self::Default => Some(data_path("inputs", &format!("{day}.txt"))),
+            Self::Path(path) => Some(path.clone()),
+            Self::Stdin => None,
+            Self::Example(None) => Some(data_path("examples", &format!("{day}.txt"))),
+            Self::Example(Some(part)) => Some(data_path("examples", &format!("{day}-{part}.txt"))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => f.write_str("puzzle input"),
+            Self::Path(path) => write!(f, "input \"{}\"", path.display()),
+            Self::Stdin => f.write_str("stdin"),
+            Self::Example(None) => f.write_str("example"),
+            Self::Example(Some(part)) => write!(f, "example {part}"),
+        }
+    }
+}
+
+fn data_path(folder: &str, file: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("data").join(folder).join(file)
+}
+
+/// An error which can be returned when parsing an [`InputSource`].
+#[derive(Debug)]
+pub enum InputSourceError {
+    MissingPath,
+    InvalidExample(String),
+    Conflicting,
+}
+
+impl Error for InputSourceError {}
+
+impl Display for InputSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPath => f.write_str("`--input` expects a file path or `-` for stdin"),
+            Self::InvalidExample(found) => {
+                write!(f, "`--example` expects a part number, found: {found}")
+            }
+            Self::Conflicting => f.write_str("`--input` and `--example` cannot be combined"),
+        }
+    }
+}
+
+/// Reads the input of a solution binary, as selected by its command-line arguments.
+/// Exits the process with a readable message if the input cannot be read.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let source = InputSource::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    source.read(day).unwrap_or_else(|e| {
+        match source.path(day) {
+            Some(path) => eprintln!("Could not read {source} from {}: {e}", display(&path)),
+            None => eprintln!("Could not read {source}: {e}"),
+        }
+        process::exit(1);
+    })
+}
+
+fn display(path: &Path) -> String {
+    let cwd = env::current_dir().unwrap_or_default();
+    path.strip_prefix(&cwd)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::InputSource;
+
+    #[test]
+    fn defaults_without_flags() {
+        let source = InputSource::from_args(&["01", "--time"]).unwrap();
+        assert_eq!(source, InputSource::Default);
+    }
+
+    #[test]
+    fn parses_input_path() {
+        let source = InputSource::from_args(&["--input", "data/alice.txt"]).unwrap();
+        assert_eq!(source, InputSource::Path(PathBuf::from("data/alice.txt")));
+    }
+
+    #[test]
+    fn parses_stdin() {
+        let source = InputSource::from_args(&["--input", "-", "--time"]).unwrap();
+        assert_eq!(source, InputSource::Stdin);
+    }
+
+    #[test]
+    fn parses_examples() {
+        let source = InputSource::from_args(&["--example"]).unwrap();
+        assert_eq!(source, InputSource::Example(None));
+
+        let source = InputSource::from_args(&["--example", "--time"]).unwrap();
+        assert_eq!(source, InputSource::Example(None));
+
+        let source = InputSource::from_args(&["--example", "2"]).unwrap();
+        assert_eq!(source, InputSource::Example(Some(2)));
+    }
+
+    #[test]
+    fn rejects_invalid_flags() {
+        assert!(InputSource::from_args(&["--input"]).is_err());
+        assert!(InputSource::from_args(&["--example", "two"]).is_err());
+        assert!(InputSource::from_args(&["--input", "-", "--example"]).is_err());
+    }
+
+    #[test]
+    fn round_trips_to_args() {
+        for source in [
+            InputSource::Default,
+            InputSource::Stdin,
+            InputSource::Path(PathBuf::from("foo.txt")),
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+        ] {
+            assert_eq!(InputSource::from_args(&source.to_args()).unwrap(), source);
+        }
+    }
+}
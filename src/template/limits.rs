@@ -0,0 +1,235 @@
+/// Wall-clock and memory limits for solution runs, so that a runaway solution cannot hang `cargo solve` or `cargo all`.
+///
+/// Limits are configured in `data/limits.json`. The `default` entry applies to all days,
+/// entries keyed by day override it field by field:
+///
+/// ```json
+/// { "default": { "timeout": 60 }, "10": { "timeout": 300, "memory": "2 GiB" } }
+/// ```
+///
+/// `timeout` is given in seconds and `memory` as a byte count such as `512 MiB`.
+/// Memory is the resident set size of the solution process, which is only known on Linux.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::process::{Child, ExitStatus};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{fs, io, thread};
+
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::alloc::{format_bytes, parse_bytes};
+
+static LIMITS_FILE_PATH: &str = "./data/limits.json";
+
+/// How often a supervised child is checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The limits of a single day. `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_bytes: Option<u64>,
+}
+
+impl Limits {
+    /// The limits of `day` from the limits file. Without a file, days are unlimited.
+    pub fn for_day(day: Day) -> Self {
+        let Ok(contents) = fs::read_to_string(LIMITS_FILE_PATH) else {
+            return Self::default();
+        };
+
+        Self::parse(&contents, day).unwrap_or_else(|e| {
+            eprintln!("Ignoring limits, failed to parse \"{LIMITS_FILE_PATH}\": {e}");
+            Self::default()
+        })
+    }
+
+    fn parse(contents: &str, day: Day) -> Result<Self, String> {
+        let json = JsonValue::from_str(contents).or(Err("not a valid JSON file."))?;
+        let entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut limits = Self::default();
+
+        if let Some(entry) = entries.get("default") {
+            limits.apply(entry, "default")?;
+        }
+
+        // days may be written as `5` or `05`.
+        let entry = entries
+            .iter()
+            .find(|(key, _)| Day::from_str(key).is_ok_and(|d| d == day));
+        if let Some((key, entry)) = entry {
+            limits.apply(entry, key)?;
+        }
+
+        Ok(limits)
+    }
+
+    fn apply(&mut self, entry: &JsonValue, key: &str) -> Result<(), String> {
+        let entry = entry
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or(format!("expected `{key}` to be an object."))?;
+
+        if let Some(timeout) = entry.get("timeout") {
+            let seconds = timeout
+                .get::<f64>()
+                .filter(|s| **s > 0.0)
+                .ok_or(format!("expected `{key}.timeout` to be a positive number."))?;
+            self.timeout = Some(Duration::from_secs_f64(*seconds));
+        }
+
+        if let Some(memory) = entry.get("memory") {
+            let bytes = memory
+                .get::<String>()
+                .and_then(|s| parse_bytes(s))
+                .ok_or(format!(
+                    "expected `{key}.memory` to be a byte count like \"512 MiB\"."
+                ))?;
+            self.memory_bytes = Some(bytes);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_limited(&self) -> bool {
+        self.timeout.is_some() || self.memory_bytes.is_some()
+    }
+}
+
+/// Why a supervised child was killed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exceeded {
+    Timeout(Duration),
+    Memory(u64),
+}
+
+impl Display for Exceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Timeout(timeout) => write!(f, "timed out after {}s", timeout.as_secs_f64()),
+            Self::Memory(bytes) => {
+                write!(f, "exceeded memory limit of {}", format_bytes(*bytes))
+            }
+        }
+    }
+}
+
+/// The resident set size of a process in bytes, if the platform exposes it.
+#[cfg(target_os = "linux")]
+fn resident_bytes(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let kib: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// Wait for `child` to exit, killing it once it exceeds `limits`.
+/// Returns the exit status, and which limit was exceeded if the child was killed.
+pub fn supervise(child: &mut Child, limits: Limits) -> io::Result<(ExitStatus, Option<Exceeded>)> {
+    if !limits.is_limited() {
+        return Ok((child.wait()?, None));
+    }
+
+    let start = Instant::now();
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((status, None));
+        }
+
+        let exceeded = match limits {
+            Limits {
+                timeout: Some(timeout),
+                ..
+            } if start.elapsed() > timeout => Some(Exceeded::Timeout(timeout)),
+            Limits {
+                memory_bytes: Some(max),
+                ..
+            } if resident_bytes(child.id()).is_some_and(|bytes| bytes > max) => {
+                Some(Exceeded::Memory(max))
+            }
+            _ => None,
+        };
+
+        if let Some(exceeded) = exceeded {
+            // the child may exit on its own right before it is killed.
+            let _ = child.kill();
+            return Ok((child.wait()?, Some(exceeded)));
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Exceeded, Limits};
+    use crate::day;
+
+    #[test]
+    fn parses_limits() {
+        let contents = r#"{
+            "default": { "timeout": 60, "memory": "1 GiB" },
+            "10": { "timeout": 0.5 },
+            "03": { "memory": "512 MiB" }
+        }"#;
+
+        assert_eq!(
+            Limits::parse(contents, day!(10)).unwrap(),
+            Limits {
+                timeout: Some(Duration::from_millis(500)),
+                memory_bytes: Some(1024 * 1024 * 1024),
+            }
+        );
+        assert_eq!(
+            Limits::parse(contents, day!(3)).unwrap(),
+            Limits {
+                timeout: Some(Duration::from_secs(60)),
+                memory_bytes: Some(512 * 1024 * 1024),
+            }
+        );
+        assert_eq!(Limits::parse("{}", day!(1)).unwrap(), Limits::default());
+    }
+
+    #[test]
+    fn rejects_invalid_limits() {
+        assert!(Limits::parse(r#"{ "default": { "timeout": -1 } }"#, day!(1)).is_err());
+        assert!(Limits::parse(r#"{ "1": { "memory": 1024 } }"#, day!(1)).is_err());
+        assert!(Limits::parse("[]", day!(1)).is_err());
+    }
+
+    #[test]
+    fn formats_exceeded_limits() {
+        assert_eq!(
+            Exceeded::Timeout(Duration::from_secs(60)).to_string(),
+            "timed out after 60s"
+        );
+        assert_eq!(
+            Exceeded::Timeout(Duration::from_millis(1500)).to_string(),
+            "timed out after 1.5s"
+        );
+        assert_eq!(
+            Exceeded::Memory(512 * 1024 * 1024).to_string(),
+            "exceeded memory limit of 512.0 MiB"
+        );
+    }
+}
@@ -0,0 +1,351 @@
+/// Leveled logging for solutions.
+///
+/// Solutions log with [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace).
+/// Messages are written to stderr, so they never interfere with the results printed to stdout.
+/// Nothing is logged unless verbosity is raised with `cargo solve NN -v` (`-vv`, `-vvv`), `--verbose <level>`
+/// or the `AOC_LOG` environment variable, which is handy in tests. Logging is silenced while a part is benched.
+use std::error::Error;
+use std::fmt::{Arguments, Display};
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::{env, io};
+
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    fn from_count(count: usize) -> Option<Self> {
+        match count {
+            0 => None,
+            1 => Some(Self::Info),
+            2 => Some(Self::Debug),
+            _ => Some(Self::Trace),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = VerbosityFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1" | "info" => Ok(Self::Info),
+            "2" | "debug" => Ok(Self::Debug),
+            "3" | "trace" => Ok(Self::Trace),
+            _ => Err(VerbosityFromStrError::InvalidLevel(s.to_string())),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Info => f.write_str("info"),
+            Self::Debug => f.write_str("debug"),
+            Self::Trace => f.write_str("trace"),
+        }
+    }
+}
+
+/// A single verbosity setting: `<level>` for all parts or `<part>:<level>` for one part, e.g. `2:trace`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Directive {
+    pub part: Option<u8>,
+    pub level: Level,
+}
+
+impl FromStr for Directive {
+    type Err = VerbosityFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((part, level)) => {
+                let part = match part.trim() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(VerbosityFromStrError::InvalidPart(part.to_string())),
+                };
+                Ok(Self {
+                    part: Some(part),
+                    level: level.trim().parse()?,
+                })
+            }
+            None => Ok(Self {
+                part: None,
+                level: s.trim().parse()?,
+            }),
+        }
+    }
+}
+
+impl Display for Directive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "{part}:{}", self.level),
+            None => write!(f, "{}", self.level),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Level`] or [`Directive`].
+#[derive(Debug)]
+pub enum VerbosityFromStrError {
+    InvalidLevel(String),
+    InvalidPart(String),
+}
+
+impl Error for VerbosityFromStrError {}
+
+impl Display for VerbosityFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLevel(s) => write!(
+                f,
+                "invalid log level `{s}`, expecting `info`, `debug`, `trace` or 1-3"
+            ),
+            Self::InvalidPart(s) => write!(f, "invalid part `{s}`, expecting 1 or 2"),
+        }
+    }
+}
+
+/// The highest level logged, for all parts and per part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Verbosity {
+    all: Option<Level>,
+    parts: [Option<Level>; 2],
+}
+
+impl Verbosity {
+    /// Build the verbosity from the number of `-v` flags and a list of directives. Later directives win.
+    pub fn new(count: usize, directives: impl IntoIterator<Item = Directive>) -> Self {
+        let mut verbosity = Self {
+            all: Level::from_count(count),
+            ..Self::default()
+        };
+
+        for directive in directives {
+            verbosity.apply(directive);
+        }
+
+        verbosity
+    }
+
+    /// Parse the verbosity from the arguments of a solution binary. Invalid directives are ignored.
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Self {
+        let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+
+        let count = args
+            .iter()
+            .filter_map(|arg| arg.strip_prefix('-'))
+            .filter(|flags| !flags.is_empty() && flags.chars().all(|c| c == 'v'))
+            .map(str::len)
+            .sum();
+
+        let directives = args
+            .windows(2)
+            .filter(|pair| pair[0] == "--verbose")
+            .filter_map(|pair| pair[1].parse().ok());
+
+        Self::new(count, directives)
+    }
+
+    /// Parse a comma-separated list of directives, as found in `AOC_LOG`. Invalid directives are ignored.
+    pub fn from_list(list: &str) -> Self {
+        Self::new(0, list.split(',').filter_map(|s| s.parse().ok()))
+    }
+
+    fn apply(&mut self, directive: Directive) {
+        match directive.part {
+            Some(part) => self.parts[usize::from(part - 1)] = Some(directive.level),
+            None => self.all = Some(directive.level),
+        }
+    }
+
+    /// The highest level logged while `part` runs. Part `0` means outside of any part, e.g. in tests.
+    pub fn level_for(&self, part: u8) -> Option<Level> {
+        let specific = match part {
+            1 | 2 => self.parts[usize::from(part - 1)],
+            _ => None,
+        };
+        specific.or(self.all)
+    }
+
+    /// Arguments that select this verbosity when passed to a solution binary.
+    pub fn to_args(self) -> Vec<String> {
+        let all = self.all.map(|level| Directive { part: None, level });
+        let parts = (1..=2).filter_map(|part| {
+            self.parts[usize::from(part - 1)].map(|level| Directive {
+                part: Some(part),
+                level,
+            })
+        });
+
+        all.into_iter()
+            .chain(parts)
+            .flat_map(|directive| ["--verbose".to_string(), directive.to_string()])
+            .collect()
+    }
+
+    pub fn is_active(self) -> bool {
+        self.all.is_some() || self.parts.iter().any(Option::is_some)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+static VERBOSITY: OnceLock<Verbosity> = OnceLock::new();
+static PART: AtomicU8 = AtomicU8::new(0);
+static SILENCED: AtomicBool = AtomicBool::new(false);
+
+/// The verbosity of this process: `AOC_LOG`, overridden by command-line arguments.
+fn verbosity() -> &'static Verbosity {
+    VERBOSITY.get_or_init(|| {
+        let mut verbosity = env::var(ENV_VAR)
+            .map(|list| Verbosity::from_list(&list))
+            .unwrap_or_default();
+
+        let args: Vec<String> = env::args().collect();
+        let from_args = Verbosity::from_args(&args);
+
+        if from_args.all.is_some() {
+            verbosity.all = from_args.all;
+        }
+        for (part, level) in verbosity.parts.iter_mut().zip(from_args.parts) {
+            if level.is_some() {
+                *part = level;
+            }
+        }
+
+        verbosity
+    })
+}
+
+/// `true` if messages of `level` are logged right now. Used by the logging macros to skip formatting.
+pub fn enabled(level: Level) -> bool {
+    !SILENCED.load(Ordering::Relaxed)
+        && verbosity()
+            .level_for(PART.load(Ordering::Relaxed))
+            .is_some_and(|max| level <= max)
+}
+
+/// Write a message to stderr. Prefer the logging macros, which check [`enabled`] first.
+pub fn write(level: Level, message: Arguments) {
+    let part = PART.load(Ordering::Relaxed);
+    let mut stderr = io::stderr().lock();
+
+    let _ = if part == 0 {
+        writeln!(stderr, "{ANSI_ITALIC}[{level}]{ANSI_RESET} {message}")
+    } else {
+        writeln!(
+            stderr,
+            "{ANSI_ITALIC}[part {part} {level}]{ANSI_RESET} {message}"
+        )
+    };
+}
+
+/// Attribute messages to `part` from now on.
+pub(crate) fn set_part(part: u8) {
+    PART.store(part, Ordering::Relaxed);
+}
+
+/// Silence or resume logging, e.g. while benching.
+pub(crate) fn set_silenced(silenced: bool) {
+    SILENCED.store(silenced, Ordering::Relaxed);
+}
+
+/// Log a message at the given [`Level`].
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::template::log::enabled($level) {
+            $crate::template::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+/// Log a message at [`Level::Info`], shown with `-v` and above.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Info, $($arg)+)
+    };
+}
+
+/// Log a message at [`Level::Debug`], shown with `-vv` and above.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Debug, $($arg)+)
+    };
+}
+
+/// Log a message at [`Level::Trace`], shown with `-vvv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Trace, $($arg)+)
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Directive, Level, Verbosity};
+
+    #[test]
+    fn parses_directives() {
+        assert_eq!(
+            "debug".parse::<Directive>().unwrap(),
+            Directive {
+                part: None,
+                level: Level::Debug
+            }
+        );
+        assert_eq!(
+            "2:3".parse::<Directive>().unwrap(),
+            Directive {
+                part: Some(2),
+                level: Level::Trace
+            }
+        );
+        assert!("3:info".parse::<Directive>().is_err());
+        assert!("loud".parse::<Directive>().is_err());
+    }
+
+    #[test]
+    fn parses_verbosity_from_args() {
+        let verbosity = Verbosity::from_args(&["--example", "-vv", "--verbose", "2:trace"]);
+        assert_eq!(verbosity.level_for(1), Some(Level::Debug));
+        assert_eq!(verbosity.level_for(2), Some(Level::Trace));
+        assert_eq!(Verbosity::from_args(&verbosity.to_args()), verbosity);
+
+        assert_eq!(
+            Verbosity::from_args(&["-v", "-v"]).level_for(0),
+            Some(Level::Debug)
+        );
+        assert!(!Verbosity::from_args(&["--time", "-"]).is_active());
+    }
+
+    #[test]
+    fn selects_levels_per_part() {
+        let verbosity = Verbosity::from_list("1:trace");
+        assert_eq!(verbosity.level_for(1), Some(Level::Trace));
+        assert_eq!(verbosity.level_for(2), None);
+        assert_eq!(verbosity.level_for(0), None);
+
+        let verbosity = Verbosity::from_list("info,2:debug");
+        assert_eq!(verbosity.level_for(1), Some(Level::Info));
+        assert_eq!(verbosity.level_for(2), Some(Level::Debug));
+    }
+}
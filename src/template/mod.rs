@@ -1,16 +1,37 @@
 use std::{env, fs};
 
+pub mod alloc;
 pub mod aoc_cli;
 pub mod commands;
+pub mod fuzz;
+pub mod log;
+pub mod perf;
+pub mod profile;
 pub mod runner;
+pub mod visualize;
 
 pub use day::*;
+pub use input::*;
+pub use selection::*;
 
 mod day;
+mod history;
+mod input;
+mod limits;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
+mod report;
 mod run_multi;
+mod selection;
+mod submissions;
 mod timings;
 
+#[cfg(all(feature = "dhat-heap", feature = "alloc-stats"))]
+compile_error!(
+    "the `dhat-heap` and `alloc-stats` features both install a global allocator, enable only one."
+);
+
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
@@ -39,11 +60,15 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, `parse = <fn>` names the parser shared by both parts, which is then benched on its own.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
         $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
     };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $day, parse $parse, [part_one, 1] [part_two, 2]);
+    };
     ($day:expr, 1) => {
         $crate::solution!(@impl $day, [part_one, 1]);
     };
@@ -51,7 +76,7 @@ macro_rules! solution {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $(parse $parse:expr,)? $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -59,9 +84,14 @@ macro_rules! solution {
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(feature = "alloc-stats")]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAlloc = $crate::template::alloc::CountingAlloc;
+
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            let input = $crate::template::read_input(DAY);
+            $( run_parse($parse, &input); )?
             $( run_part($func, &input, DAY, $part); )*
         }
     };
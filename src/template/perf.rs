@@ -0,0 +1,308 @@
+/// CPU performance counters for benchmarks, read via Linux' `perf_event_open`.
+///
+/// Solution binaries built with the `perf` feature and run with `--time --perf` count instructions, cycles,
+/// cache misses and branch misses over all bench iterations of a part and report per-iteration averages.
+/// Counting needs a Linux kernel that allows it (see `/proc/sys/kernel/perf_event_paranoid`).
+/// Everywhere else, [`Counters::open`] fails and benchmarks run as usual.
+use std::fmt::Display;
+use std::io;
+
+/// The hardware events counted, in the order of [`Readings::values`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Instructions,
+    Cycles,
+    CacheMisses,
+    BranchMisses,
+}
+
+impl Event {
+    pub const ALL: [Self; 4] = [
+        Self::Instructions,
+        Self::Cycles,
+        Self::CacheMisses,
+        Self::BranchMisses,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Instructions => "instructions",
+            Self::Cycles => "cycles",
+            Self::CacheMisses => "cache misses",
+            Self::BranchMisses => "branch misses",
+        }
+    }
+}
+
+/// Counter values per bench iteration. Events the CPU does not support are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Readings {
+    pub values: [Option<f64>; 4],
+}
+
+impl Readings {
+    pub fn get(&self, event: Event) -> Option<f64> {
+        let index = Event::ALL.iter().position(|e| *e == event)?;
+        self.values[index]
+    }
+
+    /// Instructions per cycle, if both were counted.
+    pub fn ipc(&self) -> Option<f64> {
+        let cycles = self.get(Event::Cycles).filter(|c| *c > 0.0)?;
+        Some(self.get(Event::Instructions)? / cycles)
+    }
+}
+
+/// Format a count with a metric suffix, e.g. `1.2M`.
+fn format_count(value: f64) -> String {
+    match value {
+        v if v >= 1e9 => format!("{:.1}G", v / 1e9),
+        v if v >= 1e6 => format!("{:.1}M", v / 1e6),
+        v if v >= 1e3 => format!("{:.1}K", v / 1e3),
+        v => format!("{v:.0}"),
+    }
+}
+
+impl Display for Readings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+
+        for event in Event::ALL {
+            if let Some(value) = self.get(event) {
+                parts.push(format!("{} {}", format_count(value), event.label()));
+            }
+        }
+
+        if let Some(ipc) = self.ipc() {
+            parts.push(format!("{ipc:.2} IPC"));
+        }
+
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(feature = "perf", target_os = "linux"))]
+mod sys {
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    use super::Event;
+
+    const PERF_TYPE_HARDWARE: u32 = 0;
+    const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+    const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+    const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+    const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+
+    const FLAG_DISABLED: u64 = 1 << 0;
+    const FLAG_EXCLUDE_KERNEL: u64 = 1 << 5;
+    const FLAG_EXCLUDE_HV: u64 = 1 << 6;
+
+    const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+
+    const PERF_EVENT_IOC_ENABLE: libc::c_ulong = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: libc::c_ulong = 0x2401;
+    const PERF_EVENT_IOC_RESET: libc::c_ulong = 0x2403;
+
+    /// The first published version of `struct perf_event_attr` (`PERF_ATTR_SIZE_VER0`), which all kernels accept.
+    #[repr(C)]
+    #[derive(Default)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    fn config(event: Event) -> u64 {
+        match event {
+            Event::Instructions => PERF_COUNT_HW_INSTRUCTIONS,
+            Event::Cycles => PERF_COUNT_HW_CPU_CYCLES,
+            Event::CacheMisses => PERF_COUNT_HW_CACHE_MISSES,
+            Event::BranchMisses => PERF_COUNT_HW_BRANCH_MISSES,
+        }
+    }
+
+    /// Open a disabled counter for `event` of the calling thread, in user space only.
+    pub fn open(event: Event) -> io::Result<OwnedFd> {
+        #[allow(clippy::cast_possible_truncation)]
+        let attr = PerfEventAttr {
+            kind: PERF_TYPE_HARDWARE,
+            size: size_of::<PerfEventAttr>() as u32,
+            config: config(event),
+            flags: FLAG_DISABLED | FLAG_EXCLUDE_KERNEL | FLAG_EXCLUDE_HV,
+            ..PerfEventAttr::default()
+        };
+
+        // SAFETY: `attr` is a valid `perf_event_attr` of the size it declares and outlives the call.
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_perf_event_open,
+                &raw const attr,
+                0,
+                -1,
+                -1,
+                PERF_FLAG_FD_CLOEXEC,
+            )
+        };
+
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        // SAFETY: the kernel returned a new file descriptor that nothing else owns.
+        Ok(unsafe { OwnedFd::from_raw_fd(fd as i32) })
+    }
+
+    fn ioctl(fd: &OwnedFd, request: libc::c_ulong) -> io::Result<()> {
+        // SAFETY: perf ioctls without an argument only act on the counter behind `fd`.
+        if unsafe { libc::ioctl(fd.as_raw_fd(), request as _, 0) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub fn start(fd: &OwnedFd) -> io::Result<()> {
+        ioctl(fd, PERF_EVENT_IOC_RESET)?;
+        ioctl(fd, PERF_EVENT_IOC_ENABLE)
+    }
+
+    pub fn stop(fd: &OwnedFd) -> io::Result<()> {
+        ioctl(fd, PERF_EVENT_IOC_DISABLE)
+    }
+
+    pub fn read(fd: &OwnedFd) -> io::Result<u64> {
+        let mut value = 0u64;
+        // SAFETY: reads at most 8 bytes into `value`.
+        let read = unsafe { libc::read(fd.as_raw_fd(), (&raw mut value).cast(), size_of::<u64>()) };
+        if read < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(value)
+    }
+}
+
+/// Add a hint on how to fix the most common reasons for counters being unavailable.
+#[cfg(all(feature = "perf", target_os = "linux"))]
+fn explain(e: io::Error) -> io::Error {
+    let hint = match e.kind() {
+        io::ErrorKind::PermissionDenied => {
+            "the kernel forbids it, try lowering /proc/sys/kernel/perf_event_paranoid"
+        }
+        io::ErrorKind::NotFound | io::ErrorKind::Unsupported => {
+            "this CPU or virtual machine exposes no hardware counters"
+        }
+        _ => return e,
+    };
+    io::Error::new(e.kind(), format!("{hint} ({e})"))
+}
+
+/// Open counters for all [`Event`]s, see [`Counters::open`].
+pub struct Counters {
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    fds: Vec<Option<std::os::fd::OwnedFd>>,
+}
+
+impl Counters {
+    /// Open the counters of the calling thread. Fails if no event can be counted,
+    /// e.g. because the kernel forbids it or this build has no counter support.
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    pub fn open() -> io::Result<Self> {
+        let mut fds = vec![];
+        let mut last_error = None;
+
+        for event in Event::ALL {
+            match sys::open(event) {
+                Ok(fd) => fds.push(Some(fd)),
+                Err(e) => {
+                    fds.push(None);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if fds.iter().all(Option::is_none) => Err(explain(e)),
+            _ => Ok(Self { fds }),
+        }
+    }
+
+    /// Open the counters of the calling thread. This build has no counter support, so this always fails.
+    #[cfg(not(all(feature = "perf", target_os = "linux")))]
+    pub fn open() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "performance counters need Linux and the `perf` feature",
+        ))
+    }
+
+    /// Reset the counters and start counting.
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    pub fn start(&self) -> io::Result<()> {
+        self.fds.iter().flatten().try_for_each(sys::start)
+    }
+
+    /// Stop counting, returning the counts since [`Counters::start`] divided by `iterations`.
+    #[cfg(all(feature = "perf", target_os = "linux"))]
+    pub fn finish(&self, iterations: u128) -> io::Result<Readings> {
+        self.fds.iter().flatten().try_for_each(sys::stop)?;
+
+        let mut readings = Readings::default();
+        for (value, fd) in readings.values.iter_mut().zip(&self.fds) {
+            if let Some(fd) = fd {
+                #[allow(clippy::cast_precision_loss)]
+                let per_iteration = sys::read(fd)? as f64 / iterations.max(1) as f64;
+                *value = Some(per_iteration);
+            }
+        }
+
+        Ok(readings)
+    }
+
+    #[cfg(not(all(feature = "perf", target_os = "linux")))]
+    pub fn start(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "perf", target_os = "linux")))]
+    pub fn finish(&self, _iterations: u128) -> io::Result<Readings> {
+        Ok(Readings::default())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Event, Readings, format_count};
+
+    #[test]
+    fn formats_counts() {
+        assert_eq!(format_count(12.0), "12");
+        assert_eq!(format_count(1_500.0), "1.5K");
+        assert_eq!(format_count(2_340_000.0), "2.3M");
+        assert_eq!(format_count(7e9), "7.0G");
+    }
+
+    #[test]
+    fn formats_readings() {
+        let readings = Readings {
+            values: [Some(3_000_000.0), Some(1_500_000.0), None, Some(420.0)],
+        };
+        assert_eq!(readings.get(Event::CacheMisses), None);
+        assert_eq!(readings.ipc(), Some(2.0));
+        assert_eq!(
+            readings.to_string(),
+            "3.0M instructions, 1.5M cycles, 420 branch misses, 2.00 IPC"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+/// Sampling CPU profiles of solution parts, rendered as flamegraphs.
+///
+/// Solution binaries built with the `profile` feature and run with `--profile` execute each part in a loop
+/// under an in-process sampling profiler. The samples are written to `data/profiles/` as collapsed stacks
+/// (one `frame;frame;frame count` line per stack, the input format of most flamegraph tools) and as an SVG flamegraph.
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::template::Day;
+
+/// How long each part is looped while sampling.
+pub const DURATION: Duration = Duration::from_secs(5);
+
+/// Samples per second. Not a round number, so sampling does not run in lockstep with periodic work.
+pub const FREQUENCY: i32 = 997;
+
+#[must_use]
+pub fn get_profile_path(day: Day, part: u8, extension: &str) -> PathBuf {
+    PathBuf::from(format!("data/profiles/{day}-part-{part}.{extension}"))
+}
+
+/// A single line of a collapsed stack file. `frames` are ordered from the outermost call inwards.
+pub fn collapsed_line<S: AsRef<str>>(thread: &str, frames: &[S], count: isize) -> String {
+    let mut line = thread.to_string();
+
+    for frame in frames {
+        line.push(';');
+        line.push_str(frame.as_ref());
+    }
+
+    let _ = write!(line, " {count}");
+    line
+}
+
+/// Samples the runs of a part. Only present in builds with the `profile` feature.
+#[cfg(feature = "profile")]
+pub(crate) mod sampling {
+    use std::fs;
+    use std::hint::black_box;
+    use std::io;
+    use std::path::PathBuf;
+    use std::time::Instant;
+
+    use super::{DURATION, FREQUENCY, collapsed_line, get_profile_path};
+    use crate::template::Day;
+
+    /// Files written by [`profile`].
+    pub struct Profile {
+        pub collapsed: PathBuf,
+        pub flamegraph: PathBuf,
+        pub iterations: u64,
+    }
+
+    fn to_io_error(e: pprof::Error) -> io::Error {
+        io::Error::other(e)
+    }
+
+    /// Run `func` in a loop for [`DURATION`] while sampling its stacks, then write the profile of `part`.
+    pub fn profile<I: Copy, T>(
+        func: impl Fn(I) -> T,
+        input: I,
+        day: Day,
+        part: u8,
+    ) -> io::Result<Profile> {
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(FREQUENCY)
+            // unwinding through these libraries can crash the profiler.
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(to_io_error)?;
+
+        let timer = Instant::now();
+        let mut iterations = 0;
+
+        while iterations == 0 || timer.elapsed() < DURATION {
+            black_box(func(black_box(input)));
+            iterations += 1;
+        }
+
+        let report = guard.report().build().map_err(to_io_error)?;
+
+        let mut lines: Vec<String> = report
+            .data
+            .iter()
+            .map(|(frames, count)| {
+                // pprof stores the innermost frame first, inlined symbols innermost first as well.
+                let names: Vec<String> = frames
+                    .frames
+                    .iter()
+                    .rev()
+                    .flat_map(|frame| frame.iter().rev().map(pprof::Symbol::name))
+                    .collect();
+                collapsed_line(&frames.thread_name_or_id(), &names, *count)
+            })
+            .collect();
+        lines.sort_unstable();
+
+        let collapsed = get_profile_path(day, part, "folded");
+        let flamegraph = get_profile_path(day, part, "svg");
+
+        if let Some(dir) = collapsed.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(&collapsed, lines.join("\n") + "\n")?;
+
+        let mut options = pprof::flamegraph::Options::default();
+        options.title = format!("Day {day} - Part {part}");
+        report
+            .flamegraph_with_options(fs::File::create(&flamegraph)?, &mut options)
+            .map_err(to_io_error)?;
+
+        Ok(Profile {
+            collapsed,
+            flamegraph,
+            iterations,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collapsed_line, get_profile_path};
+    use crate::day;
+
+    #[test]
+    fn builds_paths() {
+        assert_eq!(
+            get_profile_path(day!(7), 2, "svg").to_str(),
+            Some("data/profiles/07-part-2.svg")
+        );
+    }
+
+    #[test]
+    fn collapses_stacks() {
+        assert_eq!(
+            collapsed_line("main", &["main", "part_one", "parse"], 42),
+            "main;main;part_one;parse 42"
+        );
+        assert_eq!(collapsed_line::<&str>("main", &[], 1), "main 1");
+    }
+}
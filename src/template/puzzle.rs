@@ -0,0 +1,195 @@
+/// Reads examples and their answers from a puzzle description downloaded by aoc-cli to `data/puzzles/NN.md`.
+///
+/// Descriptions are markdown: examples are fenced code blocks, and answers of the examples are emphasised
+/// code spans like `` `*11*` ``. Part two starts at the `--- Part Two ---` heading once it is unlocked.
+use std::fs;
+
+use crate::template::{Day, aoc_cli};
+
+/// A fenced code block of the description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The part whose section the block is in.
+    pub part: u8,
+    pub content: String,
+    /// Whether the block follows a "For example" in its part.
+    pub is_after_for_example: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub blocks: Vec<CodeBlock>,
+    /// Emphasised code values of each part, in order of appearance.
+    emphasised: [Vec<String>; 2],
+    pub has_part_two: bool,
+}
+
+impl Puzzle {
+    /// Read the stored description of `day`, if it was downloaded.
+    pub fn read(day: Day) -> Option<Self> {
+        fs::read_to_string(aoc_cli::get_puzzle_path(day))
+            .ok()
+            .map(|markdown| Self::parse(&markdown))
+    }
+
+    pub fn parse(markdown: &str) -> Self {
+        let mut puzzle = Self::default();
+        let mut part = 1;
+        let mut seen_for_example = false;
+        let mut block: Option<String> = None;
+
+        for line in markdown.lines() {
+            if line.trim_start().starts_with("```") {
+                match block.take() {
+                    Some(content) => puzzle.blocks.push(CodeBlock {
+                        part,
+                        content,
+                        is_after_for_example: seen_for_example,
+                    }),
+                    None => block = Some(String::new()),
+                }
+                continue;
+            }
+
+            if let Some(content) = &mut block {
+                content.push_str(line);
+                content.push('\n');
+                continue;
+            }
+
+            if line.contains("--- Part Two ---") {
+                part = 2;
+                seen_for_example = false;
+                puzzle.has_part_two = true;
+            }
+
+            if line.to_lowercase().contains("for example") {
+                seen_for_example = true;
+            }
+
+            puzzle.emphasised[usize::from(part - 1)].extend(emphasised_values(line));
+        }
+
+        puzzle
+    }
+
+    /// The blocks in the section of `part`.
+    pub fn blocks(&self, part: u8) -> impl Iterator<Item = &CodeBlock> {
+        self.blocks.iter().filter(move |b| b.part == part)
+    }
+
+    /// The most likely example of `part`: the largest block after "For example", else the largest block.
+    pub fn example(&self, part: u8) -> Option<&CodeBlock> {
+        let largest = |after_for_example: bool| {
+            self.blocks(part)
+                .filter(|b| b.is_after_for_example || !after_for_example)
+                .max_by_key(|b| b.content.len())
+        };
+
+        largest(true).or_else(|| largest(false))
+    }
+
+    /// The answer of the example of `part`, the last emphasised code value of its section.
+    pub fn expected_answer(&self, part: u8) -> Option<&str> {
+        self.emphasised
+            .get(usize::from(part.checked_sub(1)?))?
+            .last()
+            .map(String::as_str)
+    }
+}
+
+/// Values of the emphasised code spans of a line, e.g. `11` for `` `*11*` `` or `` *`11`* ``.
+fn emphasised_values(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find('`') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('`') else {
+            break;
+        };
+
+        let code = &after[..end];
+        let inner = code.trim_matches('*');
+        let is_emphasised_inside = inner.len() < code.len();
+        let is_emphasised_around =
+            rest[..start].ends_with('*') && after[end + 1..].starts_with('*');
+
+        if (is_emphasised_inside || is_emphasised_around) && !inner.is_empty() {
+            values.push(inner.to_string());
+        }
+
+        rest = &after[end + 1..];
+    }
+
+    values
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Puzzle, emphasised_values};
+
+    const DESCRIPTION: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+The input looks like this:
+
+```
+1 2
+```
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+Pairing them up gives a total distance of `*11*`, not `7`.
+
+\\--- Part Two ---
+----------
+
+Using the same example, the similarity score is *`31`*.
+
+Your puzzle answer was `1234`.
+";
+
+    #[test]
+    fn reads_emphasised_values() {
+        assert_eq!(
+            emphasised_values("`*11*` and *`31`* and **`CMZ`** but not `7` or `**`"),
+            ["11", "31", "CMZ"]
+        );
+    }
+
+    #[test]
+    fn parses_descriptions() {
+        let puzzle = Puzzle::parse(DESCRIPTION);
+
+        assert_eq!(puzzle.blocks.len(), 2);
+        assert!(puzzle.has_part_two);
+        assert_eq!(
+            puzzle.example(1).map(|b| b.content.as_str()),
+            Some("3   4\n4   3\n2   5\n")
+        );
+        assert_eq!(puzzle.example(2), None);
+        assert_eq!(puzzle.expected_answer(1), Some("11"));
+        assert_eq!(puzzle.expected_answer(2), Some("31"));
+    }
+
+    #[test]
+    fn falls_back_to_largest_block() {
+        let puzzle = Puzzle::parse("```\na\n```\n\n```\nbb\ncc\n```\n");
+
+        assert_eq!(
+            puzzle.example(1).map(|b| b.content.as_str()),
+            Some("bb\ncc\n")
+        );
+        assert!(!puzzle.has_part_two);
+        assert_eq!(puzzle.expected_answer(2), None);
+    }
+}
@@ -1,13 +1,21 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use tinyjson::JsonValue;
+
+use crate::template::alloc::format_bytes;
+use crate::template::history::machine_id;
+use crate::template::submissions::{self, Progress};
+use crate::template::timings::{PartTiming, Timing, Timings, format_nanos, profile_path};
+use crate::template::{Day, aoc_cli};
 
 static MARKER: &str = "<!--- benchmarking table --->";
+static CONFIG_FILE_PATH: &str = "./data/readme.json";
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
@@ -20,6 +28,15 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parser(e) => f.write_str(e),
+            Self::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
@@ -30,13 +47,13 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
@@ -52,76 +69,504 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Columns of the benchmark table, as named in the config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Day,
+    Part1,
+    Part2,
+    /// The parser on its own, for solutions that name it in `solution!`.
+    Parse,
+    Total,
+    Median,
+    Samples,
+    /// Peak memory, allocations and bytes allocated, as recorded with `cargo time --mem`.
+    Memory,
+    /// Solved parts, from the submissions and puzzle descriptions.
+    Stars,
+    Puzzle,
+    /// Total time relative to the slowest day, as a bar.
+    Bar,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Self::Day),
+            "part_1" => Ok(Self::Part1),
+            "part_2" => Ok(Self::Part2),
+            "parse" => Ok(Self::Parse),
+            "total" => Ok(Self::Total),
+            "median" => Ok(Self::Median),
+            "samples" => Ok(Self::Samples),
+            "memory" => Ok(Self::Memory),
+            "stars" => Ok(Self::Stars),
+            "puzzle" => Ok(Self::Puzzle),
+            "bar" => Ok(Self::Bar),
+            _ => Err(format!(
+                "unknown column `{s}`, expecting one of day, part_1, part_2, parse, total, median, samples, memory, stars, puzzle or bar."
+            )),
+        }
+    }
+}
+
+impl Column {
+    fn headers(self) -> &'static [&'static str] {
+        match self {
+            Self::Day => &["Day"],
+            Self::Part1 => &["Part 1"],
+            Self::Part2 => &["Part 2"],
+            Self::Parse => &["Parse"],
+            Self::Total => &["Total"],
+            Self::Median => &["Median"],
+            Self::Samples => &["Samples"],
+            Self::Memory => &["Peak memory", "Allocations", "Allocated"],
+            Self::Stars => &["Stars"],
+            Self::Puzzle => &["Puzzle"],
+            Self::Bar => &["Relative"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sort {
+    Day,
+    Fastest,
+    Slowest,
+}
+
+/// How the total below the table is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TotalFormat {
+    /// Milliseconds with two decimals, e.g. `190.00ms`.
+    Millis,
+    /// The most readable unit, e.g. `1.2s`.
+    Auto,
+    /// No total.
+    Hidden,
+}
+
+/// Layout of the benchmark table, configured in the `benchmarks` entry of `data/readme.json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableConfig {
+    /// `None` shows the day and both parts, plus memory if it was recorded.
+    columns: Option<Vec<Column>>,
+    /// Level of the table heading, `0` omits it.
+    heading: u8,
+    sort: Sort,
+    total: TotalFormat,
+    /// Glyphs of the bar column, from the smallest fraction of a cell to a full cell.
+    bar_glyphs: Vec<char>,
+    /// Width of the longest bar in cells.
+    bar_width: usize,
+    /// The machine profile whose timings are shown. `None` shows the timings of the machine that stored them.
+    reference: Option<String>,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            columns: None,
+            heading: 2,
+            sort: Sort::Day,
+            total: TotalFormat::Millis,
+            bar_glyphs: "▏▎▍▌▋▊▉█".chars().collect(),
+            bar_width: 10,
+            reference: None,
+        }
+    }
+}
+
+impl TableConfig {
+    /// Read the config. Without a config file, the defaults are used.
+    pub fn read() -> Result<Self, Error> {
+        match fs::read_to_string(CONFIG_FILE_PATH) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| Error::Parser(format!("failed to parse \"{CONFIG_FILE_PATH}\": {e}"))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let json = JsonValue::from_str(contents).or(Err("not a valid JSON file."))?;
+        let mut config = Self::default();
+
+        let Some(table) = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("benchmarks")
+        else {
+            return Ok(config);
+        };
+
+        let table = table
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected `benchmarks` to be an object.")?;
+
+        let string = |key: &str| -> Result<Option<&String>, String> {
+            table
+                .get(key)
+                .map(|v| {
+                    v.get::<String>()
+                        .ok_or(format!("expected `benchmarks.{key}` to be a string."))
+                })
+                .transpose()
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str, max: f64| -> Result<Option<usize>, String> {
+            table
+                .get(key)
+                .map(|v| {
+                    v.get::<f64>()
+                        .filter(|x| x.fract() == 0.0 && (0.0..=max).contains(*x))
+                        .map(|x| *x as usize)
+                        .ok_or(format!(
+                            "expected `benchmarks.{key}` to be a whole number up to {max}."
+                        ))
+                })
+                .transpose()
+        };
+
+        if let Some(columns) = table.get("columns") {
+            let columns = columns
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `benchmarks.columns` to be an array.")?
+                .iter()
+                .map(|c| {
+                    c.get::<String>()
+                        .ok_or_else(|| "expected columns to be strings.".to_string())
+                        .and_then(|c| c.parse())
+                })
+                .collect::<Result<_, _>>()?;
+            config.columns = Some(columns);
+        }
+
+        if let Some(heading) = number("heading", 6.0)? {
+            config.heading = u8::try_from(heading).unwrap_or_default();
+        }
+
+        if let Some(sort) = string("sort")? {
+            config.sort = match sort.as_str() {
+                "day" => Sort::Day,
+                "fastest" => Sort::Fastest,
+                "slowest" => Sort::Slowest,
+                _ => {
+                    return Err(format!(
+                        "unknown sort order `{sort}`, expecting day, fastest or slowest."
+                    ));
+                }
+            };
+        }
+
+        if let Some(total) = string("total")? {
+            config.total = match total.as_str() {
+                "millis" => TotalFormat::Millis,
+                "auto" => TotalFormat::Auto,
+                "hidden" => TotalFormat::Hidden,
+                _ => {
+                    return Err(format!(
+                        "unknown total format `{total}`, expecting millis, auto or hidden."
+                    ));
+                }
+            };
+        }
+
+        if let Some(glyphs) = string("bar")? {
+            if glyphs.is_empty() {
+                return Err("expected `benchmarks.bar` to have at least one glyph.".into());
+            }
+            config.bar_glyphs = glyphs.chars().collect();
+        }
+
+        if let Some(width) = number("bar_width", 100.0)? {
+            config.bar_width = width;
+        }
+
+        config.reference = string("reference")?.cloned();
+
+        Ok(config)
+    }
+
+    fn columns(&self, timings: &Timings) -> Vec<Column> {
+        self.columns.clone().unwrap_or_else(|| {
+            let mut columns = vec![Column::Day, Column::Part1, Column::Part2];
+            // the parse column is only shown when a day benches its parser on its own.
+            if timings.has_parse() {
+                columns.push(Column::Parse);
+            }
+            // memory columns are only shown when stats were recorded with `cargo time --mem`.
+            if timings.has_memory() {
+                columns.push(Column::Memory);
+            }
+            columns
+        })
+    }
+
+    /// A bar of `fraction` times the bar width.
+    fn bar(&self, fraction: f64) -> String {
+        let glyphs = self.bar_glyphs.len();
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let units = (fraction.clamp(0.0, 1.0) * (self.bar_width * glyphs) as f64).round() as usize;
+        // anything measurable gets at least the smallest glyph.
+        let units = if fraction > 0.0 { units.max(1) } else { 0 };
+
+        let mut bar: String =
+            std::iter::repeat_n(self.bar_glyphs[glyphs - 1], units / glyphs).collect();
+        if units % glyphs > 0 {
+            bar.push(self.bar_glyphs[units % glyphs - 1]);
+        }
+        bar
+    }
+}
+
+/// A cell with the values of both parts, e.g. `` `1.0 KiB` / `-` ``.
+fn parts_cell<T: Copy>(
+    part_1: Option<T>,
+    part_2: Option<T>,
+    value: impl Fn(T) -> String,
+) -> String {
+    let format = |x: Option<T>| x.map_or_else(|| "-".into(), &value);
+    format!("`{}` / `{}`", format(part_1), format(part_2))
+}
+
+fn puzzle_cell(day: Day) -> String {
+    match aoc_cli::get_year() {
+        Some(year) => format!(
+            "[Puzzle](https://adventofcode.com/{year}/day/{})",
+            day.into_inner()
+        ),
+        None => "-".into(),
+    }
+}
+
+/// What the table shows besides the timings.
+struct Context<'a> {
+    config: &'a TableConfig,
+    progress: &'a Progress,
+    /// Total time of the slowest day, the full length of a bar.
+    slowest: f64,
+}
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+fn cells(column: Column, timing: &Timing, context: &Context) -> Vec<String> {
+    // parts that did not finish before the run was aborted show why.
+    let missing = timing.aborted.clone().unwrap_or_else(|| "-".into());
+    let part =
+        |part: Option<PartTiming>| format!("`{}`", part.map_or(missing.clone(), |t| t.to_string()));
+    let (memory_1, memory_2) = (timing.part_1_memory, timing.part_2_memory);
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+    match column {
+        Column::Day => vec![format!(
+            "[Day {}]({})",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            get_path_for_bin(timing.day)
+        )],
+        Column::Part1 => vec![part(timing.part_1)],
+        Column::Part2 => vec![part(timing.part_2)],
+        Column::Parse => vec![format!(
+            "`{}`",
+            timing.parse.map_or_else(|| "-".into(), |t| t.to_string())
+        )],
+        Column::Total => vec![format!("`{}`", format_nanos(timing.total_nanos))],
+        Column::Median => vec![parts_cell(
+            timing.part_1.and_then(|t| t.median_nanos),
+            timing.part_2.and_then(|t| t.median_nanos),
+            format_nanos,
+        )],
+        Column::Samples => vec![parts_cell(
+            timing.part_1.and_then(|t| t.samples),
+            timing.part_2.and_then(|t| t.samples),
+            |s| s.to_string(),
+        )],
+        Column::Memory => vec![
+            parts_cell(memory_1, memory_2, |s| format_bytes(s.peak_bytes)),
+            parts_cell(memory_1, memory_2, |s| s.allocations.to_string()),
+            parts_cell(memory_1, memory_2, |s| format_bytes(s.allocated_bytes)),
+        ],
+        Column::Stars => {
+            let stars = context
+                .progress
+                .get(&timing.day)
+                .map_or(0, |parts| parts.iter().filter(|p| p.is_some()).count());
+            vec![if stars > 0 {
+                "⭐".repeat(stars)
+            } else {
+                "-".into()
+            }]
+        }
+        Column::Puzzle => vec![puzzle_cell(timing.day)],
+        Column::Bar => {
+            let fraction = if context.slowest > 0.0 {
+                timing.total_nanos / context.slowest
+            } else {
+                0.0
+            };
+            vec![context.config.bar(fraction)]
+        }
+    }
+}
+
+fn construct_table(
+    timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+    progress: &Progress,
+) -> String {
+    let columns = config.columns(&timings);
+    let headers: Vec<&str> = columns.iter().flat_map(|c| c.headers()).copied().collect();
+
+    let mut lines: Vec<String> = vec![];
+
+    if config.heading > 0 {
+        lines.push(format!("{} Benchmarks", "#".repeat(config.heading.into())));
+        lines.push(String::new());
+    }
+
+    lines.push(format!("| {} |", headers.join(" | ")));
+    lines.push(format!("|{}", " :---: |".repeat(headers.len())));
+
+    let mut data = timings.data;
+    match config.sort {
+        Sort::Day => data.sort_by_key(|t| t.day),
+        Sort::Fastest => data.sort_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos)),
+        Sort::Slowest => data.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    let context = Context {
+        config,
+        progress,
+        slowest: data.iter().map(|t| t.total_nanos).fold(0.0, f64::max),
+    };
+
+    for timing in &data {
+        let row: Vec<String> = columns
+            .iter()
+            .flat_map(|column| cells(*column, timing, &context))
+            .collect();
+        lines.push(format!("| {} |", row.join(" | ")));
+    }
+
+    match config.total {
+        TotalFormat::Millis => {
+            lines.push(String::new());
+            lines.push(format!("**Total: {total_millis:.2}ms**"));
+        }
+        TotalFormat::Auto => {
+            lines.push(String::new());
+            lines.push(format!("**Total: {}**", format_nanos(total_millis * 1e6)));
+        }
+        TotalFormat::Hidden => {}
+    }
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+    progress: &Progress,
+) -> Result<(), Error> {
+    let table = construct_table(timings, total_millis, config, progress);
+    replace_section(s, MARKER, &table)
+}
+
+/// Replace the section between the first and last occurrence of `marker` with `content`, keeping the markers.
+/// A single marker is expanded into a section.
+pub fn replace_section(s: &mut String, marker: &str, content: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(
+        positions.pos_start..positions.pos_end,
+        &format!("{marker}\n{content}\n{marker}"),
+    );
     Ok(())
 }
 
+/// Update the readme with `timings`, or with the timings of the reference machine if one is configured.
 pub fn update(timings: Timings) -> Result<(), Error> {
     let path = "README.md";
+    let config = TableConfig::read()?;
+
+    let timings = match &config.reference {
+        Some(reference) if *reference != machine_id() => Timings::read_profile(reference)
+            .ok_or_else(|| {
+                Error::Parser(format!(
+                    "no benchmarks of the reference machine `{reference}` found in \"{}\".",
+                    profile_path(reference)
+                ))
+            })?,
+        _ => timings,
+    };
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(
+        &mut readme,
+        timings,
+        total_millis,
+        &config,
+        &submissions::progress(),
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::collections::BTreeMap;
+
+    use super::{MARKER, TableConfig, update_content};
+    use crate::day;
+    use crate::template::alloc::AllocStats;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+
+    fn millis(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1e6,
+            samples: Some(100),
+            median_nanos: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: millis(10.0),
+                    part_2: millis(20.0),
                     total_nanos: 3e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: millis(30.0),
+                    part_2: millis(40.0),
                     total_nanos: 7e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    part_1: millis(40.0),
+                    part_2: millis(50.0),
                     total_nanos: 9e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
             ],
         }
@@ -131,28 +576,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +640,14 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -168,10 +655,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +667,131 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_memory = Some(AllocStats {
+            peak_bytes: 2048,
+            allocations: 12,
+            allocated_bytes: 512,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            timings,
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Part 2 | Peak memory | Allocations | Allocated |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `2.0 KiB` / `-` | `12` / `-` | `512 B` / `-` |"
+        ));
+        assert!(s.contains(
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | `-` / `-` | `-` / `-` | `-` / `-` |"
+        ));
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = millis(5.0);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            timings,
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Part 2 | Parse |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `-` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | `5.0ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_aborted_runs() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2 = None;
+        timings.data[2].aborted = Some("timed out after 60s".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            timings,
+            190.0,
+            &TableConfig::default(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `40.0ms` | `timed out after 60s` |"));
+    }
+
+    #[test]
+    fn parses_table_config() {
+        let config = TableConfig::parse(
+            r##"{ "benchmarks": { "columns": ["day", "total", "bar"], "heading": 3, "sort": "slowest", "total": "auto", "bar": "#", "bar_width": 4, "reference": "desktop" } }"##,
+        )
+        .unwrap();
+        assert_eq!(config.heading, 3);
+        assert_eq!(config.bar_glyphs, ['#']);
+        assert_eq!(config.reference.as_deref(), Some("desktop"));
+
+        assert_eq!(TableConfig::parse("{}").unwrap(), TableConfig::default());
+        assert!(TableConfig::parse(r#"{ "benchmarks": { "columns": ["parse"] } }"#).is_ok());
+        assert!(TableConfig::parse(r#"{ "benchmarks": { "columns": ["parsing"] } }"#).is_err());
+        assert!(TableConfig::parse(r#"{ "benchmarks": { "heading": 7 } }"#).is_err());
+        assert!(TableConfig::parse(r#"{ "benchmarks": { "sort": "random" } }"#).is_err());
+    }
+
+    #[test]
+    fn draws_bars() {
+        let config = TableConfig::default();
+        assert_eq!(config.bar(1.0), "██████████");
+        assert_eq!(config.bar(0.55), "█████▌");
+        assert_eq!(config.bar(0.0001), "▏");
+        assert_eq!(config.bar(0.0), "");
+    }
+
+    #[test]
+    fn format_configured_benchmarks() {
+        let config = TableConfig::parse(
+            r##"{ "benchmarks": { "columns": ["day", "parse", "total", "samples", "stars", "bar"], "heading": 0, "sort": "slowest", "total": "auto", "bar": "#", "bar_width": 4 } }"##,
+        )
+        .unwrap();
+
+        let mut timings = get_mock_timings();
+        timings.data[2].parse = timings.data[2].part_1;
+
+        // stars come from the solved parts, not from the benchmarks.
+        let progress = BTreeMap::from([
+            (day!(2), [Some(Some(1_733_000_000)), None]),
+            (day!(4), [Some(None), Some(None)]),
+        ]);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, &config, &progress).unwrap();
+
+        let expected = [
+            "<!--- benchmarking table --->",
+            "| Day | Parse | Total | Samples | Stars | Relative |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `90.0s` | `100` / `100` | ⭐⭐ | #### |",
+            "| [Day 2](./src/bin/02.rs) | `-` | `70.0s` | `100` / `100` | ⭐ | ### |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `30.0s` | `100` / `100` | - | # |",
+            "",
+            "**Total: 190.0ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
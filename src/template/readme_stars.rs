@@ -0,0 +1,105 @@
+/// Module that updates the readme with the ⭐️ progress, in the format of `advent-readme-stars`.
+/// The progress comes from our own submission records, see `submissions`.
+use std::fs;
+
+use crate::template::readme_benchmarks::{Error, replace_section};
+use crate::template::submissions::{Progress, Solved};
+use crate::template::{Day, aoc_cli};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn star(solved: Option<Solved>) -> &'static str {
+    if solved.is_some() { "⭐" } else { " " }
+}
+
+/// Lists every day up to the last one with any progress.
+fn construct_table(progress: &Progress, year: Option<u16>) -> String {
+    let mut lines: Vec<String> = vec![match year {
+        Some(year) => format!("## {year} Results"),
+        None => "## Results".into(),
+    }];
+    lines.push(String::new());
+    lines.push("| Day | Part 1 | Part 2 |".into());
+    lines.push("| :---: | :---: | :---: |".into());
+
+    let last = progress.keys().last().map_or(0, |day| day.into_inner());
+
+    for day in (1..=last).filter_map(Day::new) {
+        let [part_1, part_2] = progress.get(&day).copied().unwrap_or_default();
+        let name = match year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        };
+        lines.push(format!("| {name} | {} | {} |", star(part_1), star(part_2)));
+    }
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, progress: &Progress, year: Option<u16>) -> Result<(), Error> {
+    let table = construct_table(progress, year);
+    replace_section(s, MARKER, &table)
+}
+
+pub fn update(progress: &Progress) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, progress, aoc_cli::get_year())?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{MARKER, update_content};
+    use crate::day;
+
+    #[test]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        assert!(update_content(&mut s, &BTreeMap::new(), Some(2025)).is_err());
+    }
+
+    #[test]
+    fn format_stars() {
+        let progress = BTreeMap::from([
+            (day!(1), [Some(Some(1_764_565_200)), Some(None)]),
+            (day!(3), [Some(None), None]),
+        ]);
+        let mut s = format!("foo\n\n{MARKER}\n\nbar");
+
+        update_content(&mut s, &progress, Some(2025)).unwrap();
+        let expected = [
+            "foo",
+            "",
+            MARKER,
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) |   |   |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            MARKER,
+            "",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+
+        // updating again replaces the section between the markers.
+        update_content(&mut s, &BTreeMap::new(), None).unwrap();
+        assert_eq!(
+            s,
+            format!(
+                "foo\n\n{MARKER}\n## Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n{MARKER}\n\nbar"
+            )
+        );
+    }
+}
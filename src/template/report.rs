@@ -0,0 +1,466 @@
+/// Renders stored benchmarks as a self-contained HTML page with an SVG bar chart per day and part.
+///
+/// The page has no scripts and loads nothing, details are shown on hover with SVG `<title>` tooltips.
+/// With a benchmark history, the chart of a part has a bar for every stored benchmark, colored by machine.
+use std::fmt::Write;
+
+use crate::template::Day;
+use crate::template::history::{Entry, format_timestamp};
+use crate::template::timings::{PartTiming, Timing, Timings, format_nanos};
+
+const CHART_WIDTH: f64 = 760.0;
+const LABEL_WIDTH: f64 = 200.0;
+const VALUE_WIDTH: f64 = 80.0;
+const ROW_HEIGHT: f64 = 22.0;
+const AXIS_HEIGHT: f64 = 24.0;
+const BAR_AREA: f64 = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+
+/// Bar colors, for the parts of the overview and for the machines of the history charts.
+const PALETTE: [&str; 6] = [
+    "#4e79a7", "#f28e2b", "#59a14f", "#e15759", "#76b7b2", "#b07aa1",
+];
+
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 800px; color: #222; }
+h2 { margin-top: 2.5rem; border-bottom: 1px solid #ddd; }
+h3 { font-size: 1rem; margin-bottom: 0.25rem; }
+svg { display: block; }
+svg text { font-size: 12px; fill: #444; }
+.grid { stroke: #e5e5e5; }
+.bar:hover { opacity: 0.75; }
+.aborted { fill: #c0392b; font-style: italic; }
+.legend span { display: inline-block; margin-right: 1rem; }
+.legend i { display: inline-block; width: 10px; height: 10px; margin-right: 4px; }
+";
+
+/// Maps durations to bar lengths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scale {
+    log: bool,
+    lo: f64,
+    hi: f64,
+}
+
+impl Scale {
+    /// A scale that fits all `values`. Log scales span whole decades, linear scales start at zero.
+    fn new(values: impl Iterator<Item = f64>, log: bool) -> Self {
+        let (min, max) = values
+            .filter(|v| *v > 0.0)
+            .fold((f64::INFINITY, 0.0_f64), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+
+        if max <= 0.0 {
+            return Self {
+                log,
+                lo: if log { 1.0 } else { 0.0 },
+                hi: if log { 10.0 } else { 1.0 },
+            };
+        }
+
+        if log {
+            let lo = 10_f64.powf(min.log10().floor());
+            let hi = 10_f64.powf(max.log10().ceil());
+            Self {
+                log,
+                lo,
+                hi: if hi > lo { hi } else { lo * 10.0 },
+            }
+        } else {
+            let step = nice_step(max / 4.0);
+            Self {
+                log,
+                lo: 0.0,
+                hi: (max / step).ceil() * step,
+            }
+        }
+    }
+
+    /// The position of `nanos` between 0 and 1.
+    fn position(&self, nanos: f64) -> f64 {
+        let position = if self.log {
+            (nanos.max(self.lo).log10() - self.lo.log10()) / (self.hi.log10() - self.lo.log10())
+        } else {
+            (nanos - self.lo) / (self.hi - self.lo)
+        };
+        position.clamp(0.0, 1.0)
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        let mut ticks = vec![self.lo];
+        if self.log {
+            while ticks.last().is_some_and(|t| *t < self.hi * 0.999) {
+                ticks.push(ticks.last().unwrap() * 10.0);
+            }
+        } else {
+            let step = nice_step(self.hi / 4.0);
+            while ticks.last().is_some_and(|t| *t < self.hi - step * 0.001) {
+                ticks.push(ticks.last().unwrap() + step);
+            }
+        }
+        ticks
+    }
+}
+
+/// Round up to 1, 2 or 5 times a power of ten.
+fn nice_step(raw: f64) -> f64 {
+    let magnitude = 10_f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .find(|m| m * magnitude >= raw)
+        .unwrap_or(10.0);
+    step * magnitude
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A row of a chart. Without `nanos`, the row shows `note` instead of a bar.
+struct Bar {
+    label: String,
+    nanos: Option<f64>,
+    note: String,
+    tooltip: String,
+    color: &'static str,
+}
+
+fn bar_chart(bars: &[Bar], scale: Scale) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let height = AXIS_HEIGHT + ROW_HEIGHT * bars.len() as f64 + 4.0;
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{CHART_WIDTH}" height="{height}" viewBox="0 0 {CHART_WIDTH} {height}">"#
+    );
+
+    for tick in scale.ticks() {
+        let x = LABEL_WIDTH + scale.position(tick) * BAR_AREA;
+        let _ = write!(
+            svg,
+            r#"<line class="grid" x1="{x:.1}" y1="{AXIS_HEIGHT}" x2="{x:.1}" y2="{height}"/><text x="{x:.1}" y="{}" text-anchor="middle">{}</text>"#,
+            AXIS_HEIGHT - 8.0,
+            escape(&format_nanos(tick)),
+        );
+    }
+
+    for (index, bar) in bars.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = AXIS_HEIGHT + ROW_HEIGHT * index as f64;
+        let text_y = y + ROW_HEIGHT / 2.0 + 4.0;
+
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{text_y:.1}" text-anchor="end">{}</text>"#,
+            LABEL_WIDTH - 8.0,
+            escape(&bar.label)
+        );
+
+        match bar.nanos {
+            Some(nanos) => {
+                let width = (scale.position(nanos) * BAR_AREA).max(1.0);
+                let _ = write!(
+                    svg,
+                    r#"<g class="bar"><title>{}</title><rect x="{LABEL_WIDTH}" y="{:.1}" width="{width:.1}" height="{:.1}" fill="{}"/><text x="{:.1}" y="{text_y:.1}">{}</text></g>"#,
+                    escape(&bar.tooltip),
+                    y + 3.0,
+                    ROW_HEIGHT - 6.0,
+                    bar.color,
+                    LABEL_WIDTH + width + 4.0,
+                    escape(&bar.note),
+                );
+            }
+            None => {
+                let _ = write!(
+                    svg,
+                    r#"<g><title>{}</title><text class="aborted" x="{LABEL_WIDTH}" y="{text_y:.1}">{}</text></g>"#,
+                    escape(&bar.tooltip),
+                    escape(&bar.note),
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+fn part_of(timing: &Timing, part: u8) -> Option<PartTiming> {
+    match part {
+        1 => timing.part_1,
+        _ => timing.part_2,
+    }
+}
+
+/// Hover details of a part: mean, median, samples and memory, as far as they were recorded.
+fn part_details(timing: &Timing, part: u8) -> String {
+    let mut details = vec![];
+
+    match part_of(timing, part) {
+        Some(time) => {
+            details.push(format!("mean {time}"));
+            if let Some(median) = time.median_nanos {
+                details.push(format!("median {}", format_nanos(median)));
+            }
+            if let Some(samples) = time.samples {
+                details.push(format!("{samples} samples"));
+            }
+        }
+        None => details.push(timing.aborted.clone().unwrap_or_else(|| "not run".into())),
+    }
+
+    let memory = match part {
+        1 => timing.part_1_memory,
+        _ => timing.part_2_memory,
+    };
+    if let Some(memory) = memory {
+        details.push(memory.to_string());
+    }
+
+    details.join(", ")
+}
+
+fn missing_note(timing: &Timing) -> String {
+    timing.aborted.clone().unwrap_or_else(|| "-".into())
+}
+
+/// All days with both parts next to each other, on one scale.
+fn overview(timings: &Timings, log: bool) -> String {
+    let scale = Scale::new(
+        timings
+            .data
+            .iter()
+            .flat_map(|t| [t.part_1, t.part_2])
+            .flatten()
+            .map(|p| p.nanos),
+        log,
+    );
+
+    let bars: Vec<Bar> = timings
+        .data
+        .iter()
+        .flat_map(|timing| {
+            (1..=2).map(move |part| {
+                let time = part_of(timing, part);
+                Bar {
+                    label: format!("Day {} · Part {part}", timing.day),
+                    nanos: time.map(|t| t.nanos),
+                    note: time.map_or_else(|| missing_note(timing), |t| t.to_string()),
+                    tooltip: format!(
+                        "Day {} part {part}: {}",
+                        timing.day,
+                        part_details(timing, part)
+                    ),
+                    color: PALETTE[usize::from(part - 1)],
+                }
+            })
+        })
+        .collect();
+
+    bar_chart(&bars, scale)
+}
+
+/// The stored benchmarks of a part, oldest first, or only the latest one without a history.
+fn part_chart(
+    day: Day,
+    part: u8,
+    latest: &Timing,
+    history: &[&Entry],
+    machines: &[&str],
+    log: bool,
+) -> String {
+    let color = |machine: &str| {
+        let index = machines.iter().position(|m| *m == machine).unwrap_or(0);
+        PALETTE[index % PALETTE.len()]
+    };
+
+    let bars: Vec<Bar> = if history.is_empty() {
+        let time = part_of(latest, part);
+        vec![Bar {
+            label: "latest".into(),
+            nanos: time.map(|t| t.nanos),
+            note: time.map_or_else(|| missing_note(latest), |t| t.to_string()),
+            tooltip: format!("Day {day} part {part}: {}", part_details(latest, part)),
+            color: PALETTE[0],
+        }]
+    } else {
+        history
+            .iter()
+            .map(|entry| {
+                let time = part_of(&entry.timing, part);
+                let commit = entry.commit.as_deref().unwrap_or("-");
+                Bar {
+                    label: format!("{} {commit}", format_timestamp(entry.timestamp)),
+                    nanos: time.map(|t| t.nanos),
+                    note: time.map_or_else(|| missing_note(&entry.timing), |t| t.to_string()),
+                    tooltip: format!(
+                        "{} UTC on {} at {commit}: {}",
+                        format_timestamp(entry.timestamp),
+                        entry.machine,
+                        part_details(&entry.timing, part)
+                    ),
+                    color: color(&entry.machine),
+                }
+            })
+            .collect()
+    };
+
+    let scale = Scale::new(bars.iter().filter_map(|b| b.nanos), log);
+    bar_chart(&bars, scale)
+}
+
+/// Render the report of `timings`, with the history entries of the same days.
+pub fn render(timings: &Timings, history: &[Entry], title: &str, log: bool) -> String {
+    let mut machines: Vec<&str> = vec![];
+    for entry in history {
+        if !machines.contains(&entry.machine.as_str()) {
+            machines.push(&entry.machine);
+        }
+    }
+
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n",
+        title = escape(title)
+    );
+
+    let _ = writeln!(
+        html,
+        "<p>Total: {}, {} scale. Hover a bar for details.</p>",
+        format_nanos(timings.total_millis() * 1e6),
+        if log { "logarithmic" } else { "linear" }
+    );
+
+    html.push_str("<h2>Overview</h2>\n<p class=\"legend\">");
+    for part in 1..=2_u8 {
+        let _ = write!(
+            html,
+            "<span><i style=\"background: {}\"></i>Part {part}</span>",
+            PALETTE[usize::from(part - 1)]
+        );
+    }
+    html.push_str("</p>\n");
+    html.push_str(&overview(timings, log));
+    html.push('\n');
+
+    if machines.len() > 1 {
+        html.push_str("<p class=\"legend\">Machines: ");
+        for (index, machine) in machines.iter().enumerate() {
+            let _ = write!(
+                html,
+                "<span><i style=\"background: {}\"></i>{}</span>",
+                PALETTE[index % PALETTE.len()],
+                escape(machine)
+            );
+        }
+        html.push_str("</p>\n");
+    }
+
+    for timing in &timings.data {
+        let entries: Vec<&Entry> = history
+            .iter()
+            .filter(|e| e.timing.day == timing.day)
+            .collect();
+
+        let _ = writeln!(html, "<h2>Day {}</h2>", timing.day);
+        for part in 1..=2 {
+            let _ = writeln!(html, "<h3>Part {part}</h3>");
+            html.push_str(&part_chart(
+                timing.day, part, timing, &entries, &machines, log,
+            ));
+            html.push('\n');
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Scale, escape, nice_step, render};
+    use crate::day;
+    use crate::template::history::Entry;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+
+    fn timing(part_1: Option<f64>, aborted: Option<&str>) -> Timing {
+        Timing {
+            day: day!(2),
+            part_1: part_1.map(|nanos| PartTiming {
+                nanos,
+                samples: Some(100),
+                median_nanos: Some(nanos * 0.9),
+            }),
+            part_2: None,
+            total_nanos: part_1.unwrap_or(0.0),
+            parse: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            aborted: aborted.map(String::from),
+        }
+    }
+
+    #[test]
+    fn scales_linearly() {
+        assert_eq!(nice_step(0.3), 0.5);
+        assert_eq!(nice_step(230.0), 500.0);
+
+        let scale = Scale::new([150.0, 900.0].into_iter(), false);
+        assert_eq!(scale.hi, 1000.0);
+        assert_eq!(scale.position(500.0), 0.5);
+        assert_eq!(scale.ticks(), [0.0, 500.0, 1000.0]);
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = Scale::new([2_000.0, 35_000_000.0].into_iter(), true);
+        assert_eq!((scale.lo, scale.hi), (1_000.0, 100_000_000.0));
+        assert!((scale.position(100_000.0) - 0.4).abs() < 1e-9);
+        assert_eq!(scale.ticks().len(), 6);
+        assert_eq!(scale.position(1.0), 0.0);
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn renders_report() {
+        let timings = Timings {
+            data: vec![timing(Some(1_500_000.0), None)],
+        };
+        let history = vec![
+            Entry {
+                timestamp: 1_764_565_200,
+                commit: Some("abc1234".into()),
+                machine: "laptop".into(),
+                timing: timing(Some(3_000_000.0), None),
+            },
+            Entry {
+                timestamp: 1_764_651_600,
+                commit: None,
+                machine: "<desktop>".into(),
+                timing: timing(None, Some("timed out after 60s")),
+            },
+        ];
+
+        let html = render(&timings, &history, "Benchmarks", true);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link") && !html.contains("src="));
+        // the overview and one chart per part.
+        assert_eq!(html.matches("<svg").count(), 3);
+        assert!(html.contains("Day 02 part 1: mean 1.5ms, median 1.4ms, 100 samples"));
+        assert!(html.contains("2025-12-01 05:00 abc1234"));
+        assert!(html.contains("timed out after 60s"));
+        assert!(html.contains("&lt;desktop&gt;"));
+        assert!(html.contains("logarithmic scale"));
+    }
+}
@@ -1,49 +1,145 @@
-use std::{collections::HashSet, io};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::{io, thread};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, InputSource};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// How solution bins are built and run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    pub is_release: bool,
+    /// Bench each part, mirroring the `--time` flag.
+    pub is_timed: bool,
+    /// Build with the `alloc-stats` feature to record allocations of each part.
+    pub measure_memory: bool,
+    /// Build with the `perf` feature and read CPU performance counters while benching.
+    pub perf_counters: bool,
+    /// Number of days run at the same time. Output is buffered and printed in day order when above 1.
+    pub jobs: usize,
+    /// Only run this part of each day.
+    pub part: Option<u8>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            is_release: false,
+            is_timed: false,
+            measure_memory: false,
+            perf_counters: false,
+            // one day at a time, like `cargo all` without `--jobs`.
+            jobs: 1,
+            part: None,
+        }
+    }
+}
+
+fn print_header(day: Day, need_space: &mut bool) {
+    if *need_space {
+        println!();
+    }
+    *need_space = true;
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Run `days_to_run`, returning their timings and allocations if they were benched or measured.
+pub fn run_multi(days_to_run: &HashSet<Day>, options: RunOptions) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let mut handle_output = |day: Day, output: Vec<String>| {
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, day);
+            timings.push(val);
+        }
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+    if options.jobs > 1 {
+        run_parallel(&days, options, |day, captured| {
+            print_header(day, &mut need_space);
+            captured.stdout.iter().for_each(|line| println!("{line}"));
+            captured.stderr.iter().for_each(|line| eprintln!("{line}"));
+            handle_output(day, captured.stdout);
         });
+    } else {
+        for day in days {
+            print_header(day, &mut need_space);
+            let output = child_commands::run_solution(day, options, &InputSource::Default).unwrap();
+            handle_output(day, output);
+        }
+    }
+
+    let timings = Timings { data: timings };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    if options.is_timed {
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
     }
+
+    (options.is_timed || options.measure_memory).then_some(timings)
+}
+
+/// Run `days` on `options.jobs` threads, passing the output of each day to `on_output` in the order of `days`.
+fn run_parallel(
+    days: &[Day],
+    options: RunOptions,
+    mut on_output: impl FnMut(Day, child_commands::Captured),
+) {
+    // build all bins up front, concurrent `cargo run`s would otherwise wait on each other's builds.
+    // errors are ignored here: a bin that fails to build reports its errors when it is run.
+    let _ = child_commands::build_solutions(options);
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let captured =
+                        child_commands::run_solution_captured(*day, options, &InputSource::Default)
+                            .unwrap();
+                    if sender.send((*day, captured)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(sender);
+
+        // days can finish in any order, hold back output until all earlier days are printed.
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter().peekable();
+
+        for (day, captured) in receiver {
+            pending.insert(day, captured);
+
+            while let Some(captured) = remaining.peek().and_then(|day| pending.remove(*day)) {
+                let day = *remaining.next().unwrap();
+                on_output(day, captured);
+            }
+        }
+    });
 }
 
 #[allow(dead_code)]
@@ -53,6 +149,15 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "the output of the solution could not be read."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -67,64 +172,215 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, RunOptions, get_path_for_bin};
+    use crate::template::limits::{self, Exceeded, Limits};
+    use crate::template::timings::{PartTiming, parse_duration};
+    use crate::template::{ANSI_BOLD, ANSI_RESET, Day, InputSource, alloc::AllocStats};
     use std::{
-        io::{BufRead, BufReader},
+        io::{BufRead, BufReader, Read},
         path::Path,
-        process::{Command, Stdio},
+        process::{Child, Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
+    /// Starts the line printed when a solution bin was killed for exceeding its [`Limits`].
+    const ABORTED_PREFIX: &str = "Aborted: ";
+
+    /// Output of a solution bin, captured instead of forwarded.
+    #[derive(Debug, Clone, Default)]
+    pub struct Captured {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+    }
+
+    /// Cargo arguments that select the build of solution bins.
+    fn build_args(options: RunOptions) -> Vec<String> {
+        let mut args = vec![];
+
+        if options.is_release {
+            args.push("--release".into());
+        }
+
+        let mut features = vec![];
+        if options.measure_memory {
+            features.push("alloc-stats");
+        }
+        if options.perf_counters {
+            features.push("perf");
+        }
+        if !features.is_empty() {
+            args.extend(["--features".into(), features.join(",")]);
+        }
+
+        args
+    }
+
+    fn run_args(day: Day, options: RunOptions, input: &InputSource) -> Vec<String> {
+        let mut args = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            day.to_string(),
+        ];
+
+        args.extend(build_args(options));
+        args.push("--".into());
+
+        if options.is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
+        }
+
+        if options.perf_counters {
+            args.push("--perf".into());
+        }
+
+        if let Some(part) = options.part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        args.extend(input.to_args());
+        args
+    }
+
+    /// Build all solution bins.
+    pub fn build_solutions(options: RunOptions) -> Result<(), Error> {
+        Command::new("cargo")
+            .args(["build", "--quiet", "--bins"])
+            .args(build_args(options))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        Ok(())
+    }
+
+    /// Build the solution bin of a single day.
+    fn build_solution(day: Day, options: RunOptions) -> Result<(), Error> {
+        Command::new("cargo")
+            .args(["build", "--quiet", "--bin", &day.to_string()])
+            .args(build_args(options))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        Ok(())
+    }
+
+    /// Spawn the solution bin of `day` with piped output. When the day is limited, the bin is built first,
+    /// so that build times do not count towards its timeout.
+    fn spawn_solution(
+        day: Day,
+        options: RunOptions,
+        input: &InputSource,
+        limits: Limits,
+    ) -> Result<Child, Error> {
+        if limits.is_limited() {
+            // errors are ignored here: a bin that fails to build reports its errors when it is run.
+            let _ = build_solution(day, options);
+        }
+
+        Ok(Command::new("cargo")
+            .args(run_args(day, options, input))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?)
+    }
+
+    fn aborted_line(exceeded: Exceeded) -> String {
+        format!("{ANSI_BOLD}Aborted:{ANSI_RESET} {exceeded}")
+    }
+
+    /// Read all lines of a pipe on a separate thread.
+    fn read_lines(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<String>> {
+        thread::spawn(move || {
+            let mut bytes = vec![];
+            let _ = pipe.read_to_end(&mut bytes);
+            String::from_utf8_lossy(&bytes)
+                .lines()
+                .map(String::from)
+                .collect()
+        })
+    }
+
+    /// Run the solution bin for a given day, capturing its output. Output of days that have not been scaffolded is empty.
+    pub fn run_solution_captured(
+        day: Day,
+        options: RunOptions,
+        input: &InputSource,
+    ) -> Result<Captured, Error> {
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(Captured::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let limits = Limits::for_day(day);
+        let mut child = spawn_solution(day, options, input, limits)?;
+
+        let stdout = read_lines(child.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = read_lines(child.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        if is_release {
-            args.push("--release");
+        let (_, exceeded) = limits::supervise(&mut child, limits)?;
+
+        let mut captured = Captured {
+            stdout: stdout.join().unwrap(),
+            stderr: stderr.join().unwrap(),
+        };
+
+        if let Some(exceeded) = exceeded {
+            captured.stdout.push(aborted_line(exceeded));
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        Ok(captured)
+    }
+
+    /// Run the solution bin for a given day
+    pub fn run_solution(
+        day: Day,
+        options: RunOptions,
+        input: &InputSource,
+    ) -> Result<Vec<String>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok(vec![]);
         }
 
+        let limits = Limits::for_day(day);
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut cmd = spawn_solution(day, options, input, limits)?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
+        let stderr_thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
+        // stdout is read on its own thread, so that the child can be killed while it does not print anything.
+        let stdout_thread = thread::spawn(move || {
+            let mut output = vec![];
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                println!("{line}");
+                output.push(line);
+            }
+            output
+        });
+
+        let (_, exceeded) = limits::supervise(&mut cmd, limits)?;
+
+        stderr_thread.join().unwrap();
+        let mut output = stdout_thread.join().unwrap();
+
+        if let Some(exceeded) = exceeded {
+            let line = aborted_line(exceeded);
             println!("{line}");
             output.push(line);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
         Ok(output)
     }
 
@@ -134,8 +390,41 @@ pub mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            parse: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            aborted: None,
         };
 
+        let mut medians = [None, None];
+
+        for line in output {
+            if let Some(reason) = strip_ansi(line).strip_prefix(ABORTED_PREFIX) {
+                timings.aborted = Some(reason.to_string());
+                continue;
+            }
+
+            if let Some((part, median)) = line.split_once(" median: ") {
+                let median = parse_duration(median.trim());
+                match part {
+                    "Part 1" => medians[0] = median,
+                    "Part 2" => medians[1] = median,
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Some((part, stats)) = line.split_once(" memory: ") else {
+                continue;
+            };
+            let memory = AllocStats::parse(stats);
+            match part {
+                "Part 1" => timings.part_1_memory = memory,
+                "Part 2" => timings.part_2_memory = memory,
+                _ => {}
+            }
+        }
+
         output
             .iter()
             .filter_map(|l| {
@@ -143,50 +432,140 @@ pub mod child_commands {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some(timing) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, timing))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, mut timing)| {
+                // parsing is part of both parts, it does not add to the total.
+                if part == "Parse" {
+                    timings.parse = Some(timing);
+                    return;
+                }
+
                 if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
+                    timing.median_nanos = medians[0];
+                    timings.part_1 = Some(timing);
                 } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                    timing.median_nanos = medians[1];
+                    timings.part_2 = Some(timing);
                 }
 
-                timings.total_nanos += nanos;
+                timings.total_nanos += timing.nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// The result of a single part, as printed by [`crate::template::runner::run_part`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PartResult {
+        pub part: u8,
+        pub answer: Option<String>,
+        pub nanos: Option<f64>,
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    /// Parse the answers (and timings, if present) of all parts from the output of a solution bin.
+    pub fn parse_results(output: &[String]) -> Vec<PartResult> {
+        let lines: Vec<String> = output
+            .iter()
+            // intermediate results are overwritten with a carriage return, keep the final one.
+            .map(|l| strip_ansi(l.rsplit('\r').next().unwrap_or_default()))
+            .collect();
+
+        let mut results = vec![];
+
+        for (index, line) in lines.iter().enumerate() {
+            let Some((part, rest)) = line
+                .strip_prefix("Part ")
+                .and_then(|l| l.split_once(": "))
+                .and_then(|(part, rest)| Some((part.parse::<u8>().ok()?, rest.trim_end())))
+            else {
+                continue;
+            };
+
+            if rest.starts_with('✖') {
+                results.push(PartResult {
+                    part,
+                    answer: None,
+                    nanos: None,
+                });
+            } else if let Some(duration) = rest.strip_prefix('▼') {
+                // multi-line results are printed below the part line.
+                let answer = lines[index + 1..]
+                    .iter()
+                    .take_while(|l| !l.starts_with("Part "))
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                results.push(PartResult {
+                    part,
+                    answer: Some(answer.trim_end().to_string()),
+                    nanos: parse_parenthesized_duration(duration),
+                });
+            } else {
+                let (answer, nanos) = match rest.rsplit_once(" (") {
+                    Some((answer, duration)) if duration.ends_with(')') => {
+                        (answer, parse_parenthesized_duration(rest))
+                    }
+                    _ => (rest, None),
+                };
+
+                results.push(PartResult {
+                    part,
+                    answer: Some(answer.to_string()),
+                    nanos,
+                });
+            }
+        }
+
+        results
+    }
+
+    fn parse_parenthesized_duration(s: &str) -> Option<f64> {
+        let inner = s.rsplit('(').next()?.split(')').next()?;
+        parse_duration(inner.split('@').next()?.trim())
+    }
+
+    fn strip_ansi(s: &str) -> String {
+        let mut res = String::with_capacity(s.len());
+        let mut chars = s.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                // skip the control sequence up to and including its final letter.
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                res.push(c);
+            }
+        }
+
+        res
+    }
+
+    fn parse_time(line: &str) -> Option<PartTiming> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
+        let (duration, samples) = line
             .split(" samples)")
             .next()?
             .split('(')
             .next_back()?
-            .split('@')
-            .next()?
-            .trim();
+            .split_once('@')?;
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        Some(PartTiming {
+            nanos: parse_duration(duration.trim())?,
+            samples: samples.trim().parse().ok(),
+            median_nanos: None,
+        })
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,7 +584,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_results};
 
         use crate::day;
 
@@ -220,8 +599,61 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let (part_1, part_2) = (res.part_1.unwrap(), res.part_2.unwrap());
+            assert_approx_eq!(part_1.nanos, 74.13_f64);
+            assert_eq!(part_1.samples, Some(100000));
+            assert_approx_eq!(part_2.nanos, 74130000_f64);
+            assert_eq!(part_2.samples, Some(99999));
+        }
+
+        #[test]
+        fn parses_parse_time() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (2.5µs @ 4000 samples)".into(),
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                ],
+                day!(1),
+            );
+            let parse = res.parse.unwrap();
+            assert_approx_eq!(parse.nanos, 2500_f64);
+            assert_eq!(parse.samples, Some(4000));
+            // parsing runs in both parts, the total does not count it twice.
+            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
+        }
+
+        #[test]
+        fn parses_memory_stats() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Part 1 memory: peak=1234567 allocations=3 allocated=2048 (1.2 MiB peak, 3 allocations, 2.0 KiB allocated)".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            let memory = res.part_1_memory.unwrap();
+            assert_eq!(memory.peak_bytes, 1_234_567);
+            assert_eq!(memory.allocations, 3);
+            assert_eq!(memory.allocated_bytes, 2048);
+            assert_eq!(res.part_2_memory, None);
+        }
+
+        #[test]
+        fn parses_medians() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.1ns @ 100000 samples)".into(),
+                    "Part 1 median: 70.5ns".into(),
+                    "Part 2: 10 (74.1ms @ 99999 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().median_nanos, Some(70.5));
+            assert_eq!(res.part_2.unwrap().median_nanos, None);
         }
 
         #[test]
@@ -235,8 +667,9 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 2000000000_f64);
+            assert_approx_eq!(res.part_2.unwrap().nanos, 100000000_f64);
+            assert_eq!(res.part_2.unwrap().samples, Some(1));
         }
 
         #[test]
@@ -253,5 +686,33 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_results() {
+            let res = parse_results(&[
+                "Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.5ms)".into(),
+                "Part 2: ✖\rPart 2: ✖             ".into(),
+            ]);
+            assert_eq!(res.len(), 2);
+            assert_eq!(res[0].part, 1);
+            assert_eq!(res[0].answer.as_deref(), Some("42"));
+            assert_approx_eq!(res[0].nanos.unwrap(), 1_500_000_f64);
+            assert_eq!(res[1].part, 2);
+            assert_eq!(res[1].answer, None);
+        }
+
+        #[test]
+        fn parses_benched_and_multiline_results() {
+            let res = parse_results(&[
+                "Part 1: 7 > benching\rPart 1: 7 (2.0µs @ 500 samples)".into(),
+                "Part 2: ▼ \rPart 2: ▼  (3ns @ 10 samples)".into(),
+                "#..".into(),
+                ".#.".into(),
+            ]);
+            assert_eq!(res[0].answer.as_deref(), Some("7"));
+            assert_approx_eq!(res[0].nanos.unwrap(), 2000_f64);
+            assert_eq!(res[1].answer.as_deref(), Some("#..\n.#."));
+            assert_approx_eq!(res[1].nanos.unwrap(), 3_f64);
+        }
     }
 }
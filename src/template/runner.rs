@@ -1,56 +1,198 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
+use std::io::{self, Write, stdout};
 use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::alloc::AllocStats;
+use crate::template::perf::{self, Readings};
+use crate::template::submissions::{self, Verdict};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, InputSource, aoc_cli, log};
+
+/// What was measured while running a part.
+struct Measurement {
+    duration: Duration,
+    samples: u128,
+    /// Median of the bench iterations, with `--time`.
+    median: Option<Duration>,
+    /// Allocations of the first run, with the `alloc-stats` feature.
+    memory: Option<AllocStats>,
+    /// Performance counters per bench iteration, with `--perf`.
+    counters: Option<io::Result<Readings>>,
+}
 
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+    if !is_part_selected(part) {
+        return;
+    }
+
     let part_str = format!("Part {part}");
+    log::set_part(part);
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, measurement) =
+        run_timed(&func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&measurement.duration, measurement.samples),
+    );
+
+    if let Some(median) = measurement.median {
+        println!("{part_str} median: {median:.1?}");
+    }
+
+    if let Some(memory) = measurement.memory {
+        println!("{part_str} memory: {} ({memory})", memory.to_record());
+    }
+
+    match measurement.counters {
+        Some(Ok(counters)) => println!("{part_str} counters: {counters}"),
+        Some(Err(e)) => println!("{part_str} counters: not available, {e}"),
+        None => {}
+    }
+
+    #[cfg(feature = "visualize")]
+    {
+        let frames = crate::template::visualize::recording::stop();
+        let options = visualize_options();
+        crate::template::visualize::recording::present(&frames, options, day, part);
+    }
+
+    #[cfg(feature = "profile")]
+    if env::args().any(|x| x == "--profile") {
+        profile_part(&func, input, day, part);
+    }
 
     if let Some(result) = result {
         submit_result(result, day, part);
     }
 }
 
+/// Run the parser shared by both parts on its own, to tell parse time apart from solve time.
+pub fn run_parse<'a, T>(func: impl Fn(&'a str) -> T, input: &'a str) {
+    let (_, measurement) = run_timed(&func, input, |_| print!("Parse:"));
+    print!("\r");
+    println!(
+        "Parse:{}",
+        format_duration(&measurement.duration, measurement.samples)
+    );
+}
+
+/// Parts can be skipped with `--part <n>`, which only runs part `n`.
+fn is_part_selected(part: u8) -> bool {
+    let args: Vec<String> = env::args().collect();
+    args.iter()
+        .position(|x| x == "--part")
+        .and_then(|index| args.get(index + 1)?.parse::<u8>().ok())
+        .is_none_or(|selected| selected == part)
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+///
+/// With the `alloc-stats` feature, the allocations of the first execution are measured as well.
+fn run_timed<I: Copy, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Measurement) {
+    #[cfg(feature = "visualize")]
+    if visualize_options().is_active() {
+        crate::template::visualize::recording::start();
+    }
+
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        #[cfg(feature = "alloc-stats")]
+        let (result, memory) = {
+            let (result, stats) = crate::template::alloc::measure(|| func(input));
+            (result, Some(stats))
+        };
+
+        #[cfg(not(feature = "alloc-stats"))]
+        let (result, memory) = (func(input), None);
+
+        (result, memory)
     };
     let base_time = timer.elapsed();
 
+    // only the first run of a part is visualized, never the benchmark iterations.
+    #[cfg(feature = "visualize")]
+    crate::template::visualize::recording::pause();
+
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let (duration, samples, median, counters) = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        (base_time, 1, None, None)
     };
 
-    (result, run.0, run.1)
+    let measurement = Measurement {
+        duration,
+        samples,
+        median,
+        memory,
+        counters,
+    };
+
+    (result, measurement)
+}
+
+/// Open performance counters if requested with `--perf`.
+fn open_counters() -> Option<io::Result<perf::Counters>> {
+    env::args()
+        .any(|x| x == "--perf")
+        .then(perf::Counters::open)
+}
+
+/// Sample the part in a loop and write its profile, see [`crate::template::profile`].
+#[cfg(feature = "profile")]
+fn profile_part<I: Copy, T>(func: impl Fn(I) -> T, input: I, day: Day, part: u8) {
+    use crate::template::profile::{DURATION, sampling};
+
+    println!("Profiling Part {part} for {}s...", DURATION.as_secs());
+
+    // logging would dominate the profile.
+    log::set_silenced(true);
+    let profile = sampling::profile(func, input, day, part);
+    log::set_silenced(false);
+
+    match profile {
+        Ok(profile) => println!(
+            "Wrote profile of {} runs to \"{}\" and \"{}\".",
+            profile.iterations,
+            profile.collapsed.display(),
+            profile.flamegraph.display()
+        ),
+        Err(e) => eprintln!("Failed to profile Part {part}: {e}"),
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Visualization options of this run. Visualization is never active while benching with `--time`.
+#[cfg(feature = "visualize")]
+fn visualize_options() -> crate::template::visualize::Options {
+    let args: Vec<String> = env::args().collect();
+    if args.iter().any(|x| x == "--time") {
+        return crate::template::visualize::Options::default();
+    }
+    crate::template::visualize::Options::from_args(&args)
+}
+
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (
+    Duration,
+    u128,
+    Option<Duration>,
+    Option<io::Result<Readings>>,
+) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -61,19 +203,42 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
 
     let mut timers: Vec<Duration> = vec![];
 
+    // logging would distort the timings, only the first run of a part logs.
+    log::set_silenced(true);
+
+    // benchmarks still run if the counters cannot be used, the error is reported with the results.
+    let counters = open_counters().map(|counters| counters.and_then(|c| c.start().map(|()| c)));
+
     for _ in 0..bench_iterations {
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
+    let readings = counters.map(|counters| counters?.finish(bench_iterations));
+
+    log::set_silenced(false);
+
     (
         #[allow(clippy::cast_possible_truncation)]
         Duration::from_nanos(average_duration(&timers) as u64),
         bench_iterations,
+        Some(median_duration(&mut timers)),
+        readings,
     )
 }
 
+fn median_duration(numbers: &mut [Duration]) -> Duration {
+    numbers.sort_unstable();
+    let mid = numbers.len() / 2;
+
+    if numbers.len().is_multiple_of(2) {
+        (numbers[mid - 1] + numbers[mid]) / 2
+    } else {
+        numbers[mid]
+    }
+}
+
 fn average_duration(numbers: &[Duration]) -> u128 {
     numbers
         .iter()
@@ -128,6 +293,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
+///  3. the solution ran against the default puzzle input.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
@@ -155,6 +321,11 @@ fn submit_result<T: Display>(
         return None;
     }
 
+    if !InputSource::from_args(&args).is_ok_and(|source| source.is_default()) {
+        eprintln!("Not submitting: results can only be submitted for the default puzzle input.");
+        return None;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -163,5 +334,15 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let output = aoc_cli::submit(day, part, &answer);
+
+    if let Ok(output) = &output {
+        let verdict = Verdict::from_response(&String::from_utf8_lossy(&output.stdout));
+        if let Err(e) = submissions::record(day, part, &answer, verdict) {
+            eprintln!("Failed to record submission: {e}");
+        }
+    }
+
+    Some(output)
 }
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::submissions::Progress;
+use crate::template::{Day, all_days};
+
+/// A set of days, parsed from a comma-separated list of days and inclusive ranges, e.g. `1,4..6,9..`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection {
+    ranges: Vec<(Day, Day)>,
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: Day) -> bool {
+        self.ranges
+            .iter()
+            .any(|(start, end)| *start <= day && day <= *end)
+    }
+
+    /// The selected days in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|day| self.contains(*day))
+    }
+}
+
+impl From<Day> for DaySelection {
+    fn from(day: Day) -> Self {
+        Self {
+            ranges: vec![(day, day)],
+        }
+    }
+}
+
+fn parse_bound(s: &str, default: Day) -> Result<Day, SelectionFromStrError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(default);
+    }
+    s.parse()
+        .map_err(|_| SelectionFromStrError::InvalidDay(s.to_string()))
+}
+
+impl FromStr for DaySelection {
+    type Err = SelectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ranges = s
+            .split(',')
+            .map(|item| match item.split_once("..") {
+                Some((start, end)) => {
+                    // `..=` reads naturally to rust programmers, ranges are inclusive either way.
+                    let end = end.strip_prefix('=').unwrap_or(end);
+                    let start = parse_bound(start, crate::day!(1))?;
+                    let end = parse_bound(end, crate::day!(25))?;
+
+                    if start > end {
+                        return Err(SelectionFromStrError::EmptyRange(item.to_string()));
+                    }
+                    Ok((start, end))
+                }
+                None => {
+                    let day = item
+                        .trim()
+                        .parse()
+                        .map_err(|_| SelectionFromStrError::InvalidDay(item.to_string()))?;
+                    Ok((day, day))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { ranges })
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}..{end}")
+                }
+            })
+            .collect();
+
+        f.write_str(&items.join(","))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`].
+#[derive(Debug)]
+pub enum SelectionFromStrError {
+    InvalidDay(String),
+    EmptyRange(String),
+}
+
+impl Error for SelectionFromStrError {}
+
+impl Display for SelectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDay(s) => write!(
+                f,
+                "invalid day `{s}`, expecting a day number between 1 and 25"
+            ),
+            Self::EmptyRange(s) => write!(f, "range `{s}` does not contain any day"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Whether a day is solved, i.e. its answers were accepted or show up in its puzzle description.
+/// With a part selected, only that part counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+}
+
+/// Which days and parts a bulk command like `cargo all` or `cargo time` runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayFilter {
+    /// Only these days. All days if `None`.
+    pub days: Option<DaySelection>,
+    /// Only this part of each day. Both parts if `None`.
+    pub part: Option<u8>,
+    /// Only days with this status.
+    pub status: Option<Status>,
+}
+
+impl DayFilter {
+    /// The days that pass this filter, judging their status by the solved parts in `progress`.
+    pub(crate) fn select(&self, progress: &Progress) -> HashSet<Day> {
+        all_days()
+            .filter(|day| self.days.as_ref().is_none_or(|days| days.contains(*day)))
+            .filter(|day| match self.status {
+                Some(Status::Solved) => self.is_solved(*day, progress),
+                Some(Status::Unsolved) => !self.is_solved(*day, progress),
+                None => true,
+            })
+            .collect()
+    }
+
+    fn is_solved(&self, day: Day, progress: &Progress) -> bool {
+        progress.get(&day).is_some_and(|parts| {
+            (1..=2)
+                .filter(|part| self.part.is_none_or(|selected| selected == *part))
+                .all(|part| parts[usize::from(part) - 1].is_some())
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{DayFilter, DaySelection, Status};
+    use crate::day;
+
+    #[test]
+    fn parses_selections() {
+        let selection: DaySelection = "1,4..6,9..=10,24..".parse().unwrap();
+        let days: Vec<u8> = selection.days().map(|d| d.into_inner()).collect();
+        assert_eq!(days, [1, 4, 5, 6, 9, 10, 24, 25]);
+        assert_eq!(selection.to_string(), "01,04..06,09..10,24..25");
+
+        let selection: DaySelection = "1,4..6,9..".parse().unwrap();
+        assert_eq!(selection.to_string(), "01,04..06,09..25");
+
+        let selection: DaySelection = "..3".parse().unwrap();
+        assert_eq!(selection.days().count(), 3);
+        assert!(selection.contains(day!(2)));
+        assert!(!selection.contains(day!(4)));
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("3..26".parse::<DaySelection>().is_err());
+        assert!("7..3".parse::<DaySelection>().is_err());
+        assert!("1,,2".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn filters_by_status() {
+        let progress = BTreeMap::from([
+            (day!(2), [Some(None), Some(Some(1_733_000_000))]),
+            (day!(3), [Some(None), None]),
+        ]);
+
+        let filter = DayFilter {
+            days: Some("1..3".parse().unwrap()),
+            status: Some(Status::Unsolved),
+            ..DayFilter::default()
+        };
+        let mut days: Vec<_> = filter.select(&progress).into_iter().collect();
+        days.sort_unstable();
+        assert_eq!(days, [day!(1), day!(3)]);
+
+        let filter = DayFilter {
+            status: Some(Status::Solved),
+            ..DayFilter::default()
+        };
+        assert_eq!(filter.select(&progress).len(), 1);
+
+        // with a part selected, only that part has to be solved.
+        let filter = DayFilter {
+            part: Some(1),
+            status: Some(Status::Solved),
+            ..DayFilter::default()
+        };
+        assert_eq!(filter.select(&progress).len(), 2);
+
+        assert_eq!(DayFilter::default().select(&progress).len(), 25);
+    }
+}
@@ -0,0 +1,323 @@
+/// Record of the answers submitted with `cargo solve --submit`, used to track progress without the leaderboard API.
+///
+/// Every submission appends one line to `data/submissions.jsonl` with the answer and the verdict of aoc-cli.
+/// Parts solved elsewhere are picked up from the "Your puzzle answer was" lines of downloaded puzzle descriptions.
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, all_days};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.jsonl";
+
+/// How Advent of Code responded to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    Rejected,
+    /// The answer was not checked, e.g. because it was submitted too soon after a wrong answer.
+    Unknown,
+}
+
+impl Verdict {
+    /// Read the verdict from the response aoc-cli printed.
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Self::Accepted
+        } else if response.contains("That's not the right answer") {
+            Self::Rejected
+        } else {
+            Self::Unknown
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "accepted" => Ok(Self::Accepted),
+            "rejected" => Ok(Self::Rejected),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Append a submission to the record, stamped with the current time.
+pub fn record(day: Day, part: u8, answer: &str, verdict: Verdict) -> io::Result<()> {
+    let submission = Submission {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    };
+
+    let mut line = JsonValue::from(&submission)
+        .stringify()
+        .map_err(io::Error::other)?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(SUBMISSIONS_FILE_PATH)?;
+    file.write_all(line.as_bytes())
+}
+
+/// Read all submissions, oldest first. Without a record, this is empty.
+pub fn read() -> Vec<Submission> {
+    let Ok(contents) = fs::read_to_string(SUBMISSIONS_FILE_PATH) else {
+        return vec![];
+    };
+
+    parse(&contents)
+}
+
+fn parse(contents: &str) -> Vec<Submission> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            let submission = JsonValue::from_str(line)
+                .map_err(|_| "not valid JSON.".to_string())
+                .and_then(|json| Submission::try_from(&json));
+
+            submission
+                .inspect_err(|e| {
+                    eprintln!(
+                        "Skipping line {} of \"{SUBMISSIONS_FILE_PATH}\": {e}",
+                        index + 1
+                    );
+                })
+                .ok()
+        })
+        .collect()
+}
+
+/// The number of parts a downloaded puzzle description shows an answer for.
+fn answered_parts(description: &str) -> u8 {
+    let count = description.matches("Your puzzle answer was").count();
+    u8::try_from(count.min(2)).unwrap_or(2)
+}
+
+/// When a part was solved. `None` if the part was solved, but not submitted with `--submit`.
+pub type Solved = Option<u64>;
+
+/// The solved parts of each day with any progress.
+pub type Progress = BTreeMap<Day, [Option<Solved>; 2]>;
+
+/// The solved parts of every day with any progress, from the submissions and puzzle descriptions.
+pub fn progress() -> Progress {
+    let descriptions = all_days().filter_map(|day| {
+        let description = fs::read_to_string(format!("data/puzzles/{day}.md")).ok()?;
+        Some((day, answered_parts(&description)))
+    });
+
+    collect_progress(&read(), descriptions)
+}
+
+fn collect_progress(
+    submissions: &[Submission],
+    descriptions: impl Iterator<Item = (Day, u8)>,
+) -> Progress {
+    let mut progress: Progress = BTreeMap::new();
+
+    for (day, answered) in descriptions.filter(|(_, answered)| *answered > 0) {
+        let parts = progress.entry(day).or_default();
+        for part in parts.iter_mut().take(answered.into()) {
+            *part = Some(None);
+        }
+    }
+
+    for submission in submissions {
+        if submission.verdict != Verdict::Accepted || !(1..=2).contains(&submission.part) {
+            continue;
+        }
+
+        let part =
+            &mut progress.entry(submission.day).or_default()[usize::from(submission.part - 1)];
+        // keep the first acceptance.
+        if !matches!(part, Some(Some(_))) {
+            *part = Some(Some(submission.timestamp));
+        }
+    }
+
+    progress
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected submission to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("expected submission.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| Day::from_str(v).ok())
+            .ok_or("expected submission.day to be a day.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("expected submission.verdict to be a string.")?
+            .parse()?;
+
+        Ok(Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submission, Verdict, answered_parts, collect_progress, parse};
+    use crate::day;
+    use crate::template::Day;
+
+    fn submission(day: u8, part: u8, timestamp: u64, verdict: Verdict) -> Submission {
+        Submission {
+            timestamp,
+            day: Day::new(day).unwrap(),
+            part,
+            answer: "42".into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Verdict::Accepted
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Verdict::Rejected
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently."),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submission = submission(3, 2, 1_764_565_200, Verdict::Rejected);
+        let line = JsonValue::from(&submission).stringify().unwrap();
+
+        assert_eq!(parse(&format!("{line}\nnot json\n\n")), [submission]);
+    }
+
+    #[test]
+    fn counts_answered_parts() {
+        assert_eq!(answered_parts("--- Day 1 ---"), 0);
+        assert_eq!(
+            answered_parts(
+                "Your puzzle answer was `12`.\n--- Part Two ---\nYour puzzle answer was `34`."
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn collects_progress() {
+        let submissions = [
+            submission(1, 1, 10, Verdict::Rejected),
+            submission(1, 1, 20, Verdict::Accepted),
+            submission(1, 1, 30, Verdict::Accepted),
+            submission(4, 2, 40, Verdict::Accepted),
+            submission(5, 1, 50, Verdict::Unknown),
+        ];
+        let progress = collect_progress(&submissions, [(day!(2), 1), (day!(4), 2)].into_iter());
+
+        assert_eq!(
+            progress.into_iter().collect::<Vec<_>>(),
+            [
+                (day!(1), [Some(Some(20)), None]),
+                (day!(2), [Some(None), None]),
+                (day!(4), [Some(None), Some(Some(40))]),
+            ]
+        );
+    }
+}
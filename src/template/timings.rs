@@ -1,17 +1,82 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap, fmt::Display, fs, io::Error, path::Path, str::FromStr, time::Duration,
+};
 use tinyjson::JsonValue;
 
 use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::history::machine_id;
+
+/// Timings are stored per machine profile, so that benchmarks of different hardware do not overwrite each other.
+static TIMINGS_DIR: &str = "./data/timings";
+/// Where timings were stored before they were kept per profile.
+static LEGACY_TIMINGS_FILE_PATH: &str = "./data/timings.json";
+
+/// The file holding the timings of `profile`, e.g. `./data/timings/laptop.json`.
+pub fn profile_path(profile: &str) -> String {
+    let name: String = profile
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{TIMINGS_DIR}/{name}.json")
+}
+
+/// The benchmark of a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Average duration of a run.
+    pub nanos: f64,
+    /// Number of runs averaged. `None` for timings stored before sample counts were recorded.
+    pub samples: Option<u64>,
+    /// Median duration of a run, if it was benched.
+    pub median_nanos: Option<f64>,
+}
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_nanos(self.nanos))
+    }
+}
+
+/// Format a duration in nanoseconds like the runner does, e.g. `74.1ms`.
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_secs_f64(nanos.max(0.0) / 1e9))
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a duration as formatted by `Debug`, e.g. `74.13ms`, to nanoseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    match s {
+        s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// The parser benched on its own, for solutions that name it in `solution!`.
+    pub parse: Option<PartTiming>,
     pub total_nanos: f64,
+    /// Allocations of the first run of each part, if measured with `--mem`.
+    pub part_1_memory: Option<AllocStats>,
+    pub part_2_memory: Option<AllocStats>,
+    /// Why the run was killed before it finished, e.g. `timed out after 60s`.
+    pub aborted: Option<String>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,19 +87,41 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
+    /// Dehydrate timings to the JSON file of this machine's profile.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
-        json.format_to(&mut file)
+        fs::create_dir_all(TIMINGS_DIR)?;
+        let mut file = fs::File::create(profile_path(&machine_id()))?;
+        json.format_to(&mut file)?;
+
+        // legacy timings were read as this machine's, and are now part of its profile.
+        if Path::new(LEGACY_TIMINGS_FILE_PATH).exists() {
+            fs::remove_file(LEGACY_TIMINGS_FILE_PATH)?;
+        }
+        Ok(())
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Rehydrate the timings of this machine's profile, merged over the legacy timings file.
+    /// If neither is present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+        let profile = Self::read_profile(&machine_id());
+        match Self::read_path(LEGACY_TIMINGS_FILE_PATH) {
+            Some(legacy) => legacy.merge(&profile.unwrap_or_default()),
+            None => profile.unwrap_or_default(),
+        }
+    }
+
+    /// Rehydrate the timings of `profile`, if it stored any.
+    pub fn read_profile(profile: &str) -> Option<Self> {
+        Self::read_path(&profile_path(profile))
+    }
+
+    /// Rehydrate timings from a JSON file.
+    pub fn read_path(path: &str) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        Timings::try_from(contents)
+            .inspect_err(|e| eprintln!("Ignoring \"{path}\": {e}"))
+            .ok()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -51,7 +138,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +147,31 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Whether any timing has allocation stats.
+    pub fn has_memory(&self) -> bool {
+        self.data
+            .iter()
+            .any(|t| t.part_1_memory.is_some() || t.part_2_memory.is_some())
+    }
+
+    /// Take the allocations of each day from `memory`, keeping the timings of `self`.
+    pub fn with_memory_of(&self, memory: &Self) -> Self {
+        let mut data = self.data.clone();
+
+        for timing in &mut data {
+            if let Some(measured) = memory.data.iter().find(|t| t.day == timing.day) {
+                timing.part_1_memory = measured.part_1_memory;
+                timing.part_2_memory = measured.part_2_memory;
+            }
+        }
+
+        Timings { data }
+    }
+
+    pub fn has_parse(&self) -> bool {
+        self.data.iter().any(|t| t.parse.is_some())
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -114,29 +226,126 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        for (key, part) in [("part_1", value.part_1), ("part_2", value.part_2)] {
+            map.insert(key.into(), part.map_or(JsonValue::Null, JsonValue::from));
+        }
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        if let Some(parse) = value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, memory) in [
+            ("part_1_memory", value.part_1_memory),
+            ("part_2_memory", value.part_2_memory),
+        ] {
+            if let Some(memory) = memory {
+                map.insert(key.into(), JsonValue::from(memory));
+            }
+        }
+
+        if let Some(aborted) = &value.aborted {
+            map.insert("aborted".into(), JsonValue::String(aborted.clone()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<PartTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        if let Some(samples) = value.samples {
+            map.insert("samples".into(), JsonValue::Number(samples as f64));
+        }
+        if let Some(median) = value.median_nanos {
+            map.insert("median_nanos".into(), JsonValue::Number(median));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/// Parse the timing of a part. Timings stored before they were numeric are strings like `"74.13ms"`,
+/// these are migrated on read and written back as numbers the next time timings are stored.
+fn part_from_json(value: Option<&JsonValue>, key: &str) -> Result<Option<PartTiming>, String> {
+    let error = || format!("Expected timing.{key} to be null, an object or a duration string.");
+
+    match value.ok_or_else(error)? {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(s) => Ok(Some(PartTiming {
+            nanos: parse_duration(s).ok_or_else(error)?,
+            samples: None,
+            median_nanos: None,
+        })),
+        JsonValue::Object(json) => {
+            let nanos = json
+                .get("nanos")
+                .and_then(|v| v.get::<f64>())
+                .ok_or(format!("Expected timing.{key}.nanos to be a number."))?;
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let samples = json
+                .get("samples")
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64);
+
+            Ok(Some(PartTiming {
+                nanos: *nanos,
+                samples,
+                median_nanos: json
+                    .get("median_nanos")
+                    .and_then(|v| v.get::<f64>())
+                    .copied(),
+            }))
+        }
+        _ => Err(error()),
+    }
+}
+
+impl From<AllocStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: AllocStats) -> Self {
+        let map: HashMap<String, JsonValue> = [
+            ("peak_bytes", value.peak_bytes),
+            ("allocations", value.allocations),
+            ("allocated_bytes", value.allocated_bytes),
+        ]
+        .into_iter()
+        .map(|(key, x)| (key.to_string(), JsonValue::Number(x as f64)))
+        .collect();
 
         JsonValue::Object(map)
     }
 }
 
+/// Parse allocation stats. `null` and missing keys, as in timings stored before stats existed, are `None`.
+fn memory_from_json(value: Option<&JsonValue>) -> Result<Option<AllocStats>, String> {
+    let Some(value) = value.filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing memory to be a JSON object.")?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let field = |key: &str| {
+        json.get(key)
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or(format!("Expected timing memory.{key} to be a number."))
+    };
+
+    Ok(Some(AllocStats {
+        peak_bytes: field("peak_bytes")?,
+        allocations: field("allocations")?,
+        allocated_bytes: field("allocated_bytes")?,
+    }))
+}
+
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
@@ -151,16 +360,6 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
-
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
@@ -168,9 +367,15 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            part_1: part_from_json(json.get("part_1"), "part_1")?,
+            part_2: part_from_json(json.get("part_2"), "part_2")?,
+            parse: json
+                .get("parse")
+                .map_or(Ok(None), |v| part_from_json(Some(v), "parse"))?,
             total_nanos,
+            part_1_memory: memory_from_json(json.get("part_1_memory"))?,
+            part_2_memory: memory_from_json(json.get("part_2_memory"))?,
+            aborted: json.get("aborted").and_then(|v| v.get::<String>()).cloned(),
         })
     }
 }
@@ -181,46 +386,159 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings, profile_path};
+
+    #[test]
+    fn sanitizes_profile_paths() {
+        assert_eq!(profile_path("desktop"), "./data/timings/desktop.json");
+        assert_eq!(
+            profile_path("Jane's M2/battery"),
+            "./data/timings/Jane-s-M2-battery.json"
+        );
+    }
+
+    fn millis(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1e6,
+            samples: Some(100),
+            median_nanos: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: millis(10.0),
+                    part_2: millis(20.0),
                     total_nanos: 3e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: millis(30.0),
+                    part_2: millis(40.0),
                     total_nanos: 7e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    part_1: millis(40.0),
                     part_2: None,
                     total_nanos: 4e+10,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{
+                alloc::AllocStats,
+                timings::{PartTiming, Timings},
+            },
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 500 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    nanos: 1_000_000_f64,
+                    samples: Some(500),
+                    median_nanos: None,
+                })
+            );
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_string_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ms", "part_2": "1.5µs", "total_nanos": 74131500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    nanos: 74_130_000_f64,
+                    samples: None,
+                    median_nanos: None,
+                })
+            );
+            assert_eq!(timing.part_2.unwrap().nanos, 1500_f64);
+            assert_eq!(timing.part_2.unwrap().to_string(), "1.5µs");
+
+            let stored = tinyjson::JsonValue::from(timings.clone())
+                .stringify()
+                .unwrap();
+            assert!(stored.contains(r#""nanos":74130000"#));
+            assert_eq!(
+                Timings::try_from(stored).unwrap().data[0].part_1,
+                timing.part_1
+            );
+
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
+        }
+
+        #[test]
+        fn handles_memory_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_memory": { "peak_bytes": 2048, "allocations": 3, "allocated_bytes": 4096 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.part_1_memory,
+                Some(AllocStats {
+                    peak_bytes: 2048,
+                    allocations: 3,
+                    allocated_bytes: 4096,
+                })
+            );
+            assert_eq!(timing.part_2_memory, None);
+            assert!(timings.has_memory());
+
+            let roundtrip = Timings::try_from(
+                tinyjson::JsonValue::from(timings.clone())
+                    .stringify()
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(roundtrip.data[0].part_1_memory, timing.part_1_memory);
+        }
+
+        #[test]
+        fn handles_aborted_runs() {
+            let json = r#"{ "data": [{ "day": "10", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "aborted": "timed out after 60s" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.aborted.as_deref(), Some("timed out after 60s"));
+            assert!(!timings.is_day_complete(day!(10)));
+
+            let roundtrip = Timings::try_from(
+                tinyjson::JsonValue::from(timings.clone())
+                    .stringify()
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(roundtrip.data[0].aborted, timing.aborted);
         }
 
         #[test]
@@ -274,18 +592,24 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
+        use super::millis;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    part_1: millis(1.0),
+                    part_2: millis(2.0),
                     total_nanos: 3_000_000_000_f64,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +617,17 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    part_1: millis(1.0),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,16 +638,21 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
+            template::alloc::AllocStats,
             template::timings::{Timing, Timings},
         };
 
@@ -334,6 +667,10 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +691,10 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    parse: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    aborted: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -365,6 +706,53 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn replaces_aborted_runs() {
+            let aborted = Timings::try_from(
+                r#"{ "data": [{ "day": "10", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "aborted": "timed out after 60s" }] }"#.to_string(),
+            )
+            .unwrap();
+            let finished = Timings::try_from(
+                r#"{ "data": [{ "day": "10", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000 }] }"#.to_string(),
+            )
+            .unwrap();
+
+            // a finished run clears the abort reason of the stored one.
+            let merged = aborted.merge(&finished);
+            assert_eq!(merged.data.len(), 1);
+            assert_eq!(merged.data[0].aborted, None);
+            assert!(merged.is_day_complete(day!(10)));
+
+            // an aborted run replaces a stored finished one.
+            let merged = finished.merge(&aborted);
+            assert_eq!(merged.data.len(), 1);
+            assert_eq!(
+                merged.data[0].aborted.as_deref(),
+                Some("timed out after 60s")
+            );
+            assert!(!merged.is_day_complete(day!(10)));
+        }
+
+        #[test]
+        fn takes_memory_of_a_separate_run() {
+            let timings = get_mock_timings();
+            let mut memory = Timings {
+                data: vec![timings.data[1].clone()],
+            };
+            memory.data[0].part_1 = None;
+            memory.data[0].part_1_memory = Some(AllocStats {
+                peak_bytes: 2048,
+                allocations: 3,
+                allocated_bytes: 4096,
+            });
+
+            let merged = timings.with_memory_of(&memory);
+            assert_eq!(merged.data.len(), timings.data.len());
+            assert_eq!(merged.data[1].part_1, timings.data[1].part_1);
+            assert_eq!(merged.data[1].part_1_memory, memory.data[0].part_1_memory);
+            assert_eq!(merged.data[0].part_1_memory, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
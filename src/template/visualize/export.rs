@@ -0,0 +1,264 @@
+/// Renders frames to images: a PNG per frame or a single animated GIF.
+///
+/// Every cell of a frame becomes a square of pixels. Colors are picked per character:
+/// empty cells (`.` and ` `) are dark, walls and items (`#` and `@`) are light, highlighted cells are gold,
+/// and every other character gets a stable accent color. Captions are not rendered.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::Frame;
+
+/// The largest edge of a rendered image in pixels, unless cells would get smaller than one pixel.
+const TARGET_SIZE: usize = 800;
+const MAX_CELL_SIZE: usize = 16;
+
+const BACKGROUND: u8 = 0;
+const FOREGROUND: u8 = 1;
+const HIGHLIGHT: u8 = 2;
+const ACCENTS: u8 = 3;
+
+/// RGB colors referenced by palette index.
+pub const PALETTE: [[u8; 3]; 11] = [
+    [0x0f, 0x0f, 0x23],
+    [0xcc, 0xcc, 0xcc],
+    [0xff, 0xd7, 0x00],
+    [0x00, 0x99, 0x00],
+    [0xe0, 0x3c, 0x31],
+    [0x3d, 0x9e, 0xe6],
+    [0x99, 0x66, 0xcc],
+    [0xff, 0x8c, 0x1a],
+    [0x2e, 0xb8, 0xa6],
+    [0xd9, 0x5f, 0xa8],
+    [0x8f, 0xb3, 0x3a],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One PNG file per frame.
+    Png,
+    /// A single animated GIF.
+    Gif,
+}
+
+impl FromStr for ExportFormat {
+    type Err = ExportFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "png" => Ok(Self::Png),
+            "gif" => Ok(Self::Gif),
+            _ => Err(ExportFormatFromStrError),
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Png => f.write_str("png"),
+            Self::Gif => f.write_str("gif"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`ExportFormat`].
+#[derive(Debug)]
+pub struct ExportFormatFromStrError;
+
+impl Error for ExportFormatFromStrError {}
+
+impl Display for ExportFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an export format of `png` or `gif`")
+    }
+}
+
+/// A frame rendered to pixels, each pixel an index into [`PALETTE`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// The pixels as RGB triplets.
+    pub fn to_rgb(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|index| PALETTE[usize::from(*index)])
+            .collect()
+    }
+}
+
+fn color_of(c: char, is_highlighted: bool) -> u8 {
+    if is_highlighted {
+        return HIGHLIGHT;
+    }
+
+    match c {
+        '.' | ' ' => BACKGROUND,
+        '#' | '@' => FOREGROUND,
+        c => {
+            #[allow(clippy::cast_possible_truncation)]
+            let accent = (u32::from(c) % u32::from(PALETTE.len() as u8 - ACCENTS)) as u8;
+            ACCENTS + accent
+        }
+    }
+}
+
+/// The size in pixels of a cell, so that a `rows` x `cols` grid fits into [`TARGET_SIZE`].
+pub fn cell_size(rows: usize, cols: usize) -> usize {
+    (TARGET_SIZE / rows.max(cols).max(1)).clamp(1, MAX_CELL_SIZE)
+}
+
+/// Render `frame` onto a canvas of `rows` x `cols` cells, each `cell_size` pixels wide.
+/// Cells outside of the frame are rendered as background.
+pub fn rasterize(frame: &Frame, rows: usize, cols: usize, cell_size: usize) -> Raster {
+    let width = cols * cell_size;
+    let height = rows * cell_size;
+    let mut pixels = vec![BACKGROUND; width * height];
+
+    for row in 0..rows.min(frame.height()) {
+        for col in 0..cols {
+            let Some(c) = frame.cell(row, col) else {
+                continue;
+            };
+
+            let color = color_of(c, frame.is_highlighted(row, col));
+            if color == BACKGROUND {
+                continue;
+            }
+
+            for y in row * cell_size..(row + 1) * cell_size {
+                let line = y * width;
+                pixels[line + col * cell_size..line + (col + 1) * cell_size].fill(color);
+            }
+        }
+    }
+
+    Raster {
+        width,
+        height,
+        pixels,
+    }
+}
+
+/// Render all frames onto a shared canvas that fits the largest frame.
+pub fn rasterize_all(frames: &[Frame]) -> Vec<Raster> {
+    let rows = frames.iter().map(Frame::height).max().unwrap_or(0);
+    let cols = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let size = cell_size(rows, cols);
+
+    frames
+        .iter()
+        .map(|frame| rasterize(frame, rows, cols, size))
+        .collect()
+}
+
+#[cfg(feature = "visualize")]
+pub(crate) mod encode {
+    use std::fs::File;
+    use std::io::{self, BufWriter};
+    use std::path::Path;
+
+    use super::{PALETTE, Raster};
+
+    fn to_io_error(e: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+        io::Error::other(e)
+    }
+
+    pub fn write_png(raster: &Raster, path: &Path) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let mut encoder = png::Encoder::new(file, raster.width as u32, raster.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(to_io_error)?;
+        writer
+            .write_image_data(&raster.to_rgb())
+            .map_err(to_io_error)?;
+        writer.finish().map_err(to_io_error)
+    }
+
+    pub fn write_gif(rasters: &[Raster], fps: u32, path: &Path) -> io::Result<()> {
+        let Some(first) = rasters.first() else {
+            return Ok(());
+        };
+
+        let (Ok(width), Ok(height)) = (u16::try_from(first.width), u16::try_from(first.height))
+        else {
+            return Err(io::Error::other("frames are too large for a GIF."));
+        };
+
+        let palette: Vec<u8> = PALETTE.iter().flatten().copied().collect();
+        let file = BufWriter::new(File::create(path)?);
+
+        let mut encoder = gif::Encoder::new(file, width, height, &palette).map_err(to_io_error)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(to_io_error)?;
+
+        // GIF delays are given in hundredths of a second.
+        let delay = u16::try_from((100 / fps.max(1)).max(1)).unwrap_or(u16::MAX);
+
+        for raster in rasters {
+            let mut frame =
+                gif::Frame::from_indexed_pixels(width, height, raster.pixels.clone(), None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(to_io_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        BACKGROUND, ExportFormat, FOREGROUND, HIGHLIGHT, cell_size, rasterize, rasterize_all,
+    };
+    use crate::template::visualize::Frame;
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("gif".parse::<ExportFormat>().unwrap(), ExportFormat::Gif);
+        assert_eq!("png".parse::<ExportFormat>().unwrap(), ExportFormat::Png);
+        assert!("jpg".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn picks_cell_sizes() {
+        assert_eq!(cell_size(10, 10), 16);
+        assert_eq!(cell_size(140, 141), 5);
+        assert_eq!(cell_size(5000, 10), 1);
+    }
+
+    #[test]
+    fn rasterizes_cells() {
+        let frame = Frame::new(["#.", ".@"]).highlight(1, 1);
+        let raster = rasterize(&frame, 2, 3, 2);
+
+        assert_eq!(raster.width, 6);
+        assert_eq!(raster.height, 4);
+        #[rustfmt::skip]
+        assert_eq!(raster.pixels, [
+            FOREGROUND, FOREGROUND, BACKGROUND, BACKGROUND, BACKGROUND, BACKGROUND,
+            FOREGROUND, FOREGROUND, BACKGROUND, BACKGROUND, BACKGROUND, BACKGROUND,
+            BACKGROUND, BACKGROUND, HIGHLIGHT, HIGHLIGHT, BACKGROUND, BACKGROUND,
+            BACKGROUND, BACKGROUND, HIGHLIGHT, HIGHLIGHT, BACKGROUND, BACKGROUND,
+        ]);
+    }
+
+    #[test]
+    fn rasterizes_frames_onto_a_shared_canvas() {
+        let rasters = rasterize_all(&[Frame::new(["#"]), Frame::new(["...", "..."])]);
+        assert_eq!(rasters[0].width, rasters[1].width);
+        assert_eq!(rasters[0].height, rasters[1].height);
+    }
+}
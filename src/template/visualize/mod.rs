@@ -0,0 +1,413 @@
+/// Step-by-step visualization of simulations.
+///
+/// Solutions describe their intermediate states as [`Frame`]s and hand them to [`emit`].
+/// Frames are only recorded when the binary is built with the `visualize` feature, which `cargo solve --visualize` enables.
+/// Without the feature, [`emit`] is a no-op and the frame-building closure is compiled out entirely.
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+pub mod export;
+
+pub use export::ExportFormat;
+
+/// `true` if frames are recorded in this build. Use it to skip bookkeeping that only serves visualization.
+pub const ENABLED: bool = cfg!(feature = "visualize");
+
+const ANSI_HIGHLIGHT: &str = "\x1b[1;33m";
+const DEFAULT_FPS: u32 = 10;
+
+/// A snapshot of a grid, with an optional set of highlighted cells and a caption.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<char>>,
+    highlights: Vec<(usize, usize)>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    /// Create a frame from rows of text.
+    pub fn new<S: AsRef<str>>(rows: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.as_ref().chars().collect())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `height` x `width` frame, asking `cell` for the character at each `(row, col)`.
+    pub fn from_fn(height: usize, width: usize, cell: impl Fn(usize, usize) -> char) -> Self {
+        Self {
+            rows: (0..height)
+                .map(|row| (0..width).map(|col| cell(row, col)).collect())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Highlight the cell at `(row, col)`.
+    #[must_use]
+    pub fn highlight(mut self, row: usize, col: usize) -> Self {
+        self.highlights.push((row, col));
+        self
+    }
+
+    /// Highlight all cells in `cells`.
+    #[must_use]
+    pub fn highlight_all(mut self, cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        self.highlights.extend(cells);
+        self
+    }
+
+    /// Attach a caption, shown above the grid.
+    #[must_use]
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn cell(&self, row: usize, col: usize) -> Option<char> {
+        self.rows.get(row)?.get(col).copied()
+    }
+
+    pub fn is_highlighted(&self, row: usize, col: usize) -> bool {
+        self.highlights.contains(&(row, col))
+    }
+
+    pub fn get_caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    /// Render the frame as plain text, e.g. for dumping to a file.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        if let Some(caption) = &self.caption {
+            let _ = writeln!(text, "{caption}");
+        }
+
+        for row in &self.rows {
+            text.extend(row);
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Render the frame with ANSI escape codes, highlighted cells in bold yellow.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+
+        if let Some(caption) = &self.caption {
+            let _ = writeln!(text, "{ANSI_BOLD}{caption}{ANSI_RESET}");
+        }
+
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for (col_index, c) in row.iter().enumerate() {
+                if self.is_highlighted(row_index, col_index) {
+                    let _ = write!(text, "{ANSI_HIGHLIGHT}{c}{ANSI_RESET}");
+                } else {
+                    text.push(*c);
+                }
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// Hand a frame to the visualization. `frame` is only called when frames are being recorded.
+#[cfg(feature = "visualize")]
+pub fn emit(frame: impl FnOnce() -> Frame) {
+    recording::push(frame);
+}
+
+/// Hand a frame to the visualization. This build does not record frames, so this does nothing.
+#[cfg(not(feature = "visualize"))]
+#[inline(always)]
+pub fn emit(_frame: impl FnOnce() -> Frame) {}
+
+/* -------------------------------------------------------------------------- */
+
+/// How recorded frames are presented, parsed from the arguments of `cargo solve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Play the frames as an animation in the terminal.
+    pub play: bool,
+    /// Write each frame to `data/visualizations/NN/part-N/`.
+    pub dump: bool,
+    /// Render the frames to images in `data/visualizations/NN/`.
+    pub export: Option<ExportFormat>,
+    pub fps: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            play: false,
+            dump: false,
+            export: None,
+            fps: DEFAULT_FPS,
+        }
+    }
+}
+
+impl Options {
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Self {
+        let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+
+        let fps = args
+            .iter()
+            .position(|x| *x == "--fps")
+            .and_then(|index| args.get(index + 1)?.parse().ok())
+            .filter(|fps| *fps > 0)
+            .unwrap_or(DEFAULT_FPS);
+
+        let export = args
+            .iter()
+            .position(|x| *x == "--export")
+            .and_then(|index| args.get(index + 1)?.parse().ok());
+
+        Self {
+            play: args.contains(&"--visualize"),
+            dump: args.contains(&"--dump-frames"),
+            export,
+            fps,
+        }
+    }
+
+    /// Arguments that select these options when passed to a solution binary.
+    pub fn to_args(self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.play {
+            args.push("--visualize".into());
+        }
+        if self.dump {
+            args.push("--dump-frames".into());
+        }
+        if let Some(format) = self.export {
+            args.extend(["--export".into(), format.to_string()]);
+        }
+        if self.fps != DEFAULT_FPS {
+            args.extend(["--fps".into(), self.fps.to_string()]);
+        }
+
+        args
+    }
+
+    pub fn is_active(self) -> bool {
+        self.play || self.dump || self.export.is_some()
+    }
+}
+
+#[must_use]
+pub fn get_frames_dir(day: Day, part: u8) -> PathBuf {
+    PathBuf::from(format!("data/visualizations/{day}/part-{part}"))
+}
+
+#[must_use]
+pub fn get_gif_path(day: Day, part: u8) -> PathBuf {
+    PathBuf::from(format!("data/visualizations/{day}/part-{part}.gif"))
+}
+
+/// Records frames while a part runs. Only present in builds with the `visualize` feature.
+#[cfg(feature = "visualize")]
+pub(crate) mod recording {
+    use std::io::{Write, stdout};
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+    use std::{fs, io, thread};
+
+    use super::export::{self, encode};
+    use super::{ExportFormat, Frame, Options, get_frames_dir, get_gif_path};
+    use crate::template::Day;
+
+    static RECORDING: AtomicBool = AtomicBool::new(false);
+    static FRAMES: Mutex<Vec<Frame>> = Mutex::new(vec![]);
+
+    pub fn push(frame: impl FnOnce() -> Frame) {
+        if RECORDING.load(Ordering::Relaxed) {
+            let frame = frame();
+            FRAMES.lock().unwrap().push(frame);
+        }
+    }
+
+    pub fn start() {
+        FRAMES.lock().unwrap().clear();
+        RECORDING.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop recording, keeping the frames recorded so far.
+    pub fn pause() {
+        RECORDING.store(false, Ordering::Relaxed);
+    }
+
+    /// Stop recording and take the recorded frames.
+    pub fn stop() -> Vec<Frame> {
+        pause();
+        std::mem::take(&mut *FRAMES.lock().unwrap())
+    }
+
+    /// Play and / or dump the frames recorded for `part`, as configured by `options`.
+    pub fn present(frames: &[Frame], options: Options, day: Day, part: u8) {
+        if frames.is_empty() {
+            return;
+        }
+
+        if options.play {
+            play(frames, options.fps);
+        }
+
+        if options.dump {
+            match dump(frames, day, part) {
+                Ok(dir) => println!("Wrote {} frames to \"{}\".", frames.len(), dir.display()),
+                Err(e) => eprintln!("Failed to write frames: {e}"),
+            }
+        }
+
+        if let Some(format) = options.export {
+            match export_frames(frames, format, options.fps, day, part) {
+                Ok(path) => println!(
+                    "Exported {} frames as {format} to \"{}\".",
+                    frames.len(),
+                    path.display()
+                ),
+                Err(e) => eprintln!("Failed to export frames: {e}"),
+            }
+        }
+    }
+
+    fn play(frames: &[Frame], fps: u32) {
+        let delay = Duration::from_secs(1) / fps;
+        let mut stdout = stdout();
+        let mut previous_lines = 0;
+
+        for frame in frames {
+            if previous_lines > 0 {
+                // move the cursor back to the start of the previous frame and clear it.
+                print!("\x1b[{previous_lines}F\x1b[J");
+            }
+            print!("{}", frame.to_ansi());
+            let _ = stdout.flush();
+
+            previous_lines = frame.height() + usize::from(frame.get_caption().is_some());
+            thread::sleep(delay);
+        }
+    }
+
+    /// Create `dir`, removing files with `extension` left over from a previous run that may have had more steps.
+    fn prepare_dir(dir: &Path, extension: &str) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == extension) {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dump(frames: &[Frame], day: Day, part: u8) -> io::Result<PathBuf> {
+        let dir = get_frames_dir(day, part);
+        prepare_dir(&dir, "txt")?;
+
+        for (index, frame) in frames.iter().enumerate() {
+            fs::write(dir.join(format!("{:04}.txt", index + 1)), frame.to_text())?;
+        }
+
+        Ok(dir)
+    }
+
+    fn export_frames(
+        frames: &[Frame],
+        format: ExportFormat,
+        fps: u32,
+        day: Day,
+        part: u8,
+    ) -> io::Result<PathBuf> {
+        let rasters = export::rasterize_all(frames);
+
+        match format {
+            ExportFormat::Gif => {
+                let path = get_gif_path(day, part);
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                encode::write_gif(&rasters, fps, &path)?;
+                Ok(path)
+            }
+            ExportFormat::Png => {
+                let dir = get_frames_dir(day, part);
+                prepare_dir(&dir, "png")?;
+                for (index, raster) in rasters.iter().enumerate() {
+                    encode::write_png(raster, &dir.join(format!("{:04}.png", index + 1)))?;
+                }
+                Ok(dir)
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExportFormat, Frame, Options};
+
+    #[test]
+    fn builds_frames() {
+        let frame = Frame::from_fn(2, 3, |row, col| if row == col { '#' } else { '.' })
+            .highlight(1, 1)
+            .caption("round 1");
+
+        assert_eq!(frame.height(), 2);
+        assert_eq!(frame.width(), 3);
+        assert_eq!(frame.cell(0, 0), Some('#'));
+        assert_eq!(frame.cell(2, 0), None);
+        assert!(frame.is_highlighted(1, 1));
+        assert!(!frame.is_highlighted(0, 0));
+        assert_eq!(frame.to_text(), "round 1\n#..\n.#.\n");
+        assert_eq!(
+            frame,
+            Frame::new(["#..", ".#."])
+                .highlight(1, 1)
+                .caption("round 1")
+        );
+    }
+
+    #[test]
+    fn highlights_cells_in_ansi() {
+        let frame = Frame::new(["ab"]).highlight(0, 1);
+        assert_eq!(frame.to_ansi(), "a\x1b[1;33mb\x1b[0m\n");
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = Options::from_args(&["--visualize", "--fps", "30", "--export", "gif"]);
+        assert!(options.play);
+        assert!(!options.dump);
+        assert_eq!(options.export, Some(ExportFormat::Gif));
+        assert_eq!(options.fps, 30);
+        assert_eq!(Options::from_args(&options.to_args()), options);
+
+        assert!(!Options::from_args(&["--time"]).is_active());
+        assert_eq!(Options::from_args(&["--fps", "0"]).fps, 10);
+    }
+}